@@ -0,0 +1,447 @@
+#![warn(clippy::pedantic)]
+//! Derive macros for [cardpack](https://crates.io/crates/cardpack)'s `Ranked`, `Suited` and
+//! `Decked` traits, enabled in cardpack with the `derive` feature.
+//!
+//! The ranks and suits of the deck are listed, from highest to lowest, in `#[ranks]` and
+//! `#[suits]` attributes on the type. Each one is named by its
+//! [`FluentName`](https://docs.rs/cardpack/latest/cardpack/localization/struct.FluentName.html),
+//! with underscores for hyphens, so `big_joker` is `big-joker`.
+//!
+//! * `#[ranks(ace = 'A', king = 'K')]` - the rank name and its index char.
+//! * `#[suits(spades('♠', 'S'), hearts('♥', 'H', color = Red))]` - the suit name, its symbol and
+//!   index chars, and optionally its [color](https://docs.rs/colored/latest/colored/enum.Color.html).
+//!
+//! Index chars are parsed ignoring case, and suit symbols are parsed as well.
+//!
+//! Every name has to be in cardpack's fluent templates, or be added with `LayeredLoader` or
+//! `FluentName::declare()`, before the deck is used, since that's where the cards get their
+//! indexes, weights and long names from. The derive can't see the templates, so a name that
+//! isn't there still compiles, but building the deck, or parsing or making one of its cards,
+//! panics instead of giving blank cards:
+//!
+//! ```should_panic
+//! use cardpack::prelude::*;
+//!
+//! #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+//! #[derive(Ranked, Suited, Decked)]
+//! #[ranks(benny = 'B', ace = 'A')]
+//! #[suits(spades('♠', 'S'))]
+//! pub struct Odd {}
+//!
+//! // Unknown FluentName: `benny`.
+//! let pile = Pile::<Odd, Odd>::from_str("BS");
+//! ```
+//!
+//! Declaring the names first fixes it:
+//!
+//! ```
+//! use cardpack::prelude::*;
+//! use cardpack::localization::LayeredLoader;
+//!
+//! #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+//! #[derive(Ranked, Suited, Decked)]
+//! #[ranks(benny = 'B', ace = 'A')]
+//! #[suits(spades('♠', 'S'))]
+//! pub struct Odd {}
+//!
+//! LayeredLoader::global()
+//!     .add_resource(&FluentName::US_ENGLISH, "benny-index = B\nbenny-weight = 13")
+//!     .unwrap();
+//!
+//! assert_eq!(Odd::deck().to_string(), "B♠ A♠");
+//! ```
+//!
+//! Deriving `Decked` also defines a card literal macro for the deck, named after the type in
+//! `snake_case`, that's usable in the rest of the module after the type. It takes a card's
+//! index for a `Card`, or a list of them for a `Pile`, and any other index is a compile error.
+//! `Decked` also derives `Literal`, so cardpack's own `card!` and `pile!` macros work too:
+//!
+//! ```
+//! use cardpack::prelude::*;
+//!
+//! #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+//! #[derive(Ranked, Suited, Decked)]
+//! #[ranks(ace = 'A', king = 'K')]
+//! #[suits(spades('♠', 'S'), hearts('♥', 'H', color = Red))]
+//! pub struct Tiny {}
+//!
+//! let mut deck = Tiny::deck();
+//!
+//! assert_eq!(deck.to_string(), "A♠ K♠ A♥ K♥");
+//! assert_eq!(deck.draw_first().unwrap(), tiny!(AS));
+//! assert_eq!(deck, tiny!(KS AH KH));
+//! assert_eq!(pile!(Tiny: "KS AH KH"), tiny!(KS AH KH));
+//! assert_eq!(Pile::<Tiny, Tiny>::from_str("ks a♥ KH").unwrap(), tiny!(KS AH KH));
+//! assert_eq!(Tiny::rank_names(), vec![French::ACE, French::KING]);
+//! assert_eq!(Tiny::colors().len(), 1);
+//! ```
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use std::str::FromStr;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, DeriveInput, Ident, LitChar, Token};
+
+/// Derives `Ranked` from a `#[ranks(name = 'I', ...)]` attribute.
+#[proc_macro_derive(Ranked, attributes(ranks))]
+pub fn derive_ranked(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, ranked).into()
+}
+
+/// Derives `Suited` from a `#[suits(name('symbol', 'I', color = Color), ...)]` attribute.
+#[proc_macro_derive(Suited, attributes(suits))]
+pub fn derive_suited(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, suited).into()
+}
+
+/// Derives `Decked<Self, Self>` and `Literal`, and a card literal macro for the deck, from the
+/// `#[ranks]` and `#[suits]` attributes.
+#[proc_macro_derive(Decked, attributes(ranks, suits))]
+pub fn derive_decked(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, decked).into()
+}
+
+struct RankAttr {
+    name: String,
+    index: char,
+}
+
+struct SuitAttr {
+    name: String,
+    symbol: char,
+    index: char,
+    color: Option<Ident>,
+}
+
+/// The arguments to a suit, such as `('♥', 'H', color = Red)`.
+struct SuitArgs {
+    symbol: char,
+    index: char,
+    color: Option<Ident>,
+}
+
+impl Parse for SuitArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let symbol: LitChar = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let index: LitChar = input.parse()?;
+        let mut color = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "color" {
+                return Err(syn::Error::new(key.span(), "expected `color = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            color = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(SuitArgs {
+            symbol: symbol.value(),
+            index: index.value(),
+            color,
+        })
+    }
+}
+
+fn expand(input: &DeriveInput, f: fn(&DeriveInput) -> syn::Result<TokenStream2>) -> TokenStream2 {
+    f(input).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+/// Turns a rank or suit ident into its fluent name, such as `big_joker` into `big-joker`.
+fn fluent_name(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").replace('_', "-")
+}
+
+fn ranks(input: &DeriveInput) -> syn::Result<Vec<RankAttr>> {
+    let mut ranks = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("ranks")) {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.require_ident()?;
+            let index: LitChar = meta.value()?.parse()?;
+            ranks.push(RankAttr {
+                name: fluent_name(ident),
+                index: index.value(),
+            });
+            Ok(())
+        })?;
+    }
+    if ranks.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "expected a `#[ranks(name = 'I', ...)]` attribute",
+        ));
+    }
+    Ok(ranks)
+}
+
+fn suits(input: &DeriveInput) -> syn::Result<Vec<SuitAttr>> {
+    let mut suits = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("suits")) {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.require_ident()?;
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let args: SuitArgs = content.parse()?;
+            suits.push(SuitAttr {
+                name: fluent_name(ident),
+                symbol: args.symbol,
+                index: args.index,
+                color: args.color,
+            });
+            Ok(())
+        })?;
+    }
+    if suits.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "expected a `#[suits(name('symbol', 'I'), ...)]` attribute",
+        ));
+    }
+    Ok(suits)
+}
+
+/// The chars that the ranks can be parsed from.
+fn rank_chars(ranks: &[RankAttr]) -> Vec<char> {
+    ranks.iter().flat_map(|r| cased(r.index)).collect()
+}
+
+/// The chars that the suits can be parsed from.
+fn suit_chars(suits: &[SuitAttr]) -> Vec<char> {
+    suits
+        .iter()
+        .flat_map(|s| std::iter::once(s.symbol).chain(cased(s.index)))
+        .collect()
+}
+
+/// The index char, along with its other case, if it has one.
+fn cased(c: char) -> Vec<char> {
+    let mut chars = vec![c];
+    chars.extend(c.to_lowercase().chain(c.to_uppercase()).filter(|o| *o != c));
+    chars.dedup();
+    chars
+}
+
+fn ranked(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let type_name = ident.to_string();
+    let ranks = ranks(input)?;
+
+    let names: Vec<&String> = ranks.iter().map(|r| &r.name).collect();
+    let chars = rank_chars(&ranks);
+    let arms = ranks.iter().map(|r| {
+        let chars = cased(r.index);
+        let name = &r.name;
+        quote! { #(#chars)|* => Some(::cardpack::__private::known_name(#type_name, #name)), }
+    });
+
+    Ok(quote! {
+        impl ::cardpack::types::traits::Ranked for #ident {
+            fn rank_chars() -> Vec<char> {
+                vec![#(#chars),*]
+            }
+
+            fn rank_names() -> Vec<&'static str> {
+                vec![#(::cardpack::__private::known_name(#type_name, #names)),*]
+            }
+
+            fn rank_for_char(c: &char) -> Option<&'static str> {
+                match c {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+
+            fn type_name() -> &'static str {
+                #type_name
+            }
+        }
+    })
+}
+
+fn suited(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let type_name = ident.to_string();
+    let suits = suits(input)?;
+
+    let names: Vec<&String> = suits.iter().map(|s| &s.name).collect();
+    let chars = suit_chars(&suits);
+    let arms = suits.iter().map(|s| {
+        let chars: Vec<char> = std::iter::once(s.symbol).chain(cased(s.index)).collect();
+        let name = &s.name;
+        quote! { #(#chars)|* => Some(::cardpack::__private::known_name(#type_name, #name)), }
+    });
+    let colors = suits.iter().filter_map(|s| {
+        let index = s.index;
+        s.color.as_ref().map(|color| {
+            quote! { colors.insert(#index, ::cardpack::__private::Color::#color); }
+        })
+    });
+
+    Ok(quote! {
+        impl ::cardpack::types::traits::Suited for #ident {
+            fn colors() -> ::std::collections::HashMap<char, ::cardpack::__private::Color> {
+                #[allow(unused_mut)]
+                let mut colors = ::std::collections::HashMap::new();
+                #(#colors)*
+                colors
+            }
+
+            fn suit_chars() -> Vec<char> {
+                vec![#(#chars),*]
+            }
+
+            fn suit_names() -> Vec<&'static str> {
+                vec![#(::cardpack::__private::known_name(#type_name, #names)),*]
+            }
+
+            fn suit_for_char(c: &char) -> Option<&'static str> {
+                match c {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+
+            fn type_name() -> &'static str {
+                #type_name
+            }
+        }
+    })
+}
+
+fn decked(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let type_name = ident.to_string();
+    let ranks = ranks(input)?;
+    let suits = suits(input)?;
+    let macro_name = format_ident!("{}", snake_case(&ident.to_string()));
+    let (rank_chars, suit_chars) = (rank_chars(&ranks), suit_chars(&suits));
+
+    let mut arms = Vec::new();
+    for suit in &suits {
+        for rank in &ranks {
+            let index = format!("{}{}", rank.index, suit.index);
+            let token = TokenStream2::from_str(&index)
+                .ok()
+                .filter(|t| t.clone().into_iter().count() == 1)
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        Span::call_site(),
+                        format!("the card index `{index}` isn't a single token"),
+                    )
+                })?;
+            let (rank, suit) = (&rank.name, &suit.name);
+            arms.push(quote! {
+                (#token) => {
+                    ::cardpack::types::card::Card::<#ident, #ident>::new(
+                        ::cardpack::types::rank::Rank::<#ident>::new(
+                            ::cardpack::__private::known_name(#type_name, #rank),
+                        ),
+                        ::cardpack::types::suit::Suit::<#ident>::new(
+                            ::cardpack::__private::known_name(#type_name, #suit),
+                        ),
+                    )
+                };
+            });
+        }
+    }
+
+    Ok(quote! {
+        impl ::cardpack::types::traits::Decked<#ident, #ident> for #ident {
+            fn blank() -> ::cardpack::types::card::Card<#ident, #ident> {
+                ::cardpack::types::card::Card::<#ident, #ident>::default()
+            }
+
+            fn guide() -> Option<String> {
+                None
+            }
+        }
+
+        impl ::cardpack::types::traits::Literal for #ident {
+            type RankType = #ident;
+            type SuitType = #ident;
+
+            const RANK_CHARS: &'static [char] = &[#(#rank_chars),*];
+            const SUIT_CHARS: &'static [char] = &[#(#suit_chars),*];
+        }
+
+        #[allow(unused_macros)]
+        macro_rules! #macro_name {
+            #(#arms)*
+            (__) => {
+                ::cardpack::types::card::Card::<#ident, #ident>::default()
+            };
+            ($first:tt $($rest:tt)+) => {
+                ::cardpack::types::pile::Pile::<#ident, #ident>::from(
+                    vec![#macro_name!($first) $(, #macro_name!($rest))+]
+                )
+            };
+        }
+    })
+}
+
+fn snake_case(s: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod derive__tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn cased() {
+        assert_eq!(super::cased('A'), vec!['A', 'a']);
+        assert_eq!(super::cased('b'), vec!['b', 'B']);
+        assert_eq!(super::cased('9'), vec!['9']);
+    }
+
+    #[test]
+    fn fluent_name() {
+        assert_eq!(super::fluent_name(&format_ident!("big_joker")), "big-joker");
+    }
+
+    #[test]
+    fn snake_case() {
+        assert_eq!(super::snake_case("Tiny"), "tiny");
+        assert_eq!(super::snake_case("HouseRules"), "house_rules");
+    }
+
+    #[test]
+    fn suits() {
+        let input: DeriveInput = parse_quote! {
+            #[suits(spades('♠', 'S'), hearts('♥', 'H', color = Red))]
+            struct Tiny {}
+        };
+
+        let suits = super::suits(&input).unwrap();
+
+        assert_eq!(suits.len(), 2);
+        assert_eq!(suits[1].name, "hearts");
+        assert_eq!(suits[1].symbol, '♥');
+        assert_eq!(suits[1].color.as_ref().unwrap(), "Red");
+        assert!(suits[0].color.is_none());
+    }
+
+    #[test]
+    fn decked__bad_index() {
+        let input: DeriveInput = parse_quote! {
+            #[ranks(ace = 'A')]
+            #[suits(stars('★', '★'))]
+            struct Stars {}
+        };
+
+        assert!(super::decked(&input).is_err());
+        assert!(super::ranks(&parse_quote! { struct Empty {} }).is_err());
+    }
+}
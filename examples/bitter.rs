@@ -1,25 +1,25 @@
-use cardpack::decks::skat::Skat;
-use cardpack::prelude::French;
-use cardpack::types::traits::Decked;
+use cardpack::prelude::*;
 
 fn main() {
     let deck = Skat::deck();
+    let encoding = Skat::encoding();
 
     for card in deck.v() {
         println!(
             "{} \n{}",
             card.index,
-            Skat::string_guided(card.get_ckc_number())
+            Skat::binary_string_guided(encoding.encode(card).unwrap_or_default())
         );
     }
 
     let deck = French::deck();
+    let encoding = French::encoding();
 
     for card in deck.v() {
         println!(
             "{} \n{}",
             card.index,
-            Skat::string_guided(card.get_ckc_number())
+            French::binary_string_guided(encoding.encode(card).unwrap_or_default())
         );
     }
 }
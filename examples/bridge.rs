@@ -65,8 +65,18 @@ impl BridgeDirection {
         }
     }
 
+    /// The seat of the direction at a `Dealer` table, going clockwise from South.
+    fn seat(&self) -> usize {
+        match self {
+            BridgeDirection::S | BridgeDirection::Unknown => 0,
+            BridgeDirection::W => 1,
+            BridgeDirection::N => 2,
+            BridgeDirection::E => 3,
+        }
+    }
+
     fn random() -> BridgeDirection {
-        match rand::random::<u8>() % 3 {
+        match rand::random::<u8>() % 4 {
             0 => BridgeDirection::S,
             1 => BridgeDirection::W,
             2 => BridgeDirection::N,
@@ -110,10 +120,16 @@ impl BridgeBoard {
         let pack = cards.clone();
 
         let dealer = BridgeDirection::random();
-        let south = cards.draw(13).sort();
-        let west = cards.draw(13).sort();
-        let north = cards.draw(13).sort();
-        let east = cards.draw(13).sort();
+        // Seats go clockwise from South, with the button on the dealer.
+        let hands = Dealer::bridge()
+            .with_button(dealer.seat())
+            .deal(&mut cards)
+            .unwrap()
+            .sort();
+        let south = hands.seats[0].clone();
+        let west = hands.seats[1].clone();
+        let north = hands.seats[2].clone();
+        let east = hands.seats[3].clone();
 
         BridgeBoard {
             dealer,
@@ -185,8 +201,10 @@ impl BridgeBoard {
     pub fn from_pbn_deal(deal: &str) -> Self {
         let (mut direction, pbn) = BridgeBoard::split_on_direction(deal);
 
-        let mut board = BridgeBoard::default();
-        board.dealer = direction;
+        let mut board = BridgeBoard {
+            dealer: direction,
+            ..Default::default()
+        };
 
         for s in pbn.split_whitespace() {
             board.fold_in(&direction, board.to_pile(s));
@@ -283,7 +301,7 @@ impl BridgeBoard {
 
         let coll: Vec<Card<French, French>> = v
             .iter()
-            .map(|s| self.pack.card_by_index(s).unwrap().clone())
+            .map(|s| self.pack.card_by_index(s).unwrap())
             .collect();
 
         Pile::from(coll)
@@ -321,49 +339,36 @@ impl Display for BridgeBoard {
 struct BridgeCompass;
 
 impl BridgeCompass {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(board: BridgeBoard) -> String {
         let north = BridgeCompass::cell_string(board.north);
         let west = BridgeCompass::cell_string(board.west);
         let east = BridgeCompass::cell_string(board.east);
         let south = BridgeCompass::cell_string(board.south);
 
-        format!(
-            "{}",
-            BridgeCompass::compass(
-                BridgeCompass::compass_cell("NORTH", north.as_str()),
-                BridgeCompass::compass_cell("WEST", west.as_str()),
-                BridgeCompass::compass_cell("EAST", east.as_str()),
-                BridgeCompass::compass_cell("SOUTH", south.as_str()),
-            )
+        BridgeCompass::compass(
+            BridgeCompass::compass_cell("NORTH", north.as_str()),
+            BridgeCompass::compass_cell("WEST", west.as_str()),
+            BridgeCompass::compass_cell("EAST", east.as_str()),
+            BridgeCompass::compass_cell("SOUTH", south.as_str()),
         )
+        .to_string()
     }
 
     fn cell_string(cards: FrenchDeck) -> String {
         let mut v = Vec::<String>::new();
 
-        match cards.rank_index_by_suit(&Suit::<French>::new(French::SPADES), " ") {
-            Some(index) => {
-                v.push(format!("♠ {index}"));
-            }
-            None => {}
+        if let Some(index) = cards.rank_index_by_suit(&Suit::<French>::new(French::SPADES), " ") {
+            v.push(format!("♠ {index}"));
         }
-        match cards.rank_index_by_suit(&Suit::<French>::new(French::HEARTS), " ") {
-            Some(index) => {
-                v.push(format!("♥ {index}"));
-            }
-            None => {}
+        if let Some(index) = cards.rank_index_by_suit(&Suit::<French>::new(French::HEARTS), " ") {
+            v.push(format!("♥ {index}"));
         }
-        match cards.rank_index_by_suit(&Suit::<French>::new(French::DIAMONDS), " ") {
-            Some(index) => {
-                v.push(format!("♦ {index}"));
-            }
-            None => {}
+        if let Some(index) = cards.rank_index_by_suit(&Suit::<French>::new(French::DIAMONDS), " ") {
+            v.push(format!("♦ {index}"));
         }
-        match cards.rank_index_by_suit(&Suit::<French>::new(French::CLUBS), " ") {
-            Some(index) => {
-                v.push(format!("♣ {index}"));
-            }
-            None => {}
+        if let Some(index) = cards.rank_index_by_suit(&Suit::<French>::new(French::CLUBS), " ") {
+            v.push(format!("♣ {index}"));
         }
 
         v.join("\n")
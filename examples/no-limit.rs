@@ -1,17 +1,26 @@
 use cardpack::prelude::*;
 
 fn main() {
-    let pack = French::deck();
-    let mut shuffled = pack.shuffle();
+    let mut shuffled = French::deck().shuffle();
 
-    let small_blind = shuffled.draw(2);
-    let big_blind = shuffled.draw(2);
+    // Heads up, the button posts the small blind and gets the second card of each round. The
+    // board is dealt into the kitty, burning a card before the flop, the turn and the river.
+    let mut hands = Dealer::new(2)
+        .round_robin(2)
+        .burn(1)
+        .kitty(3)
+        .burn(1)
+        .kitty(1)
+        .burn(1)
+        .kitty(1)
+        .deal(&mut shuffled)
+        .unwrap();
 
-    println!("small blind: {}", small_blind.to_string());
-    println!("big blind:   {}", big_blind.to_string());
+    println!("small blind: {}", hands.seats[0]);
+    println!("big blind:   {}", hands.seats[1]);
 
     println!();
-    println!("flop : {}", shuffled.draw(3).to_string());
-    println!("turn : {}", shuffled.draw(1).to_string());
-    println!("river: {}", shuffled.draw(1).to_string());
+    println!("flop : {}", hands.kitty.draw(3));
+    println!("turn : {}", hands.kitty.draw(1));
+    println!("river: {}", hands.kitty.draw(1));
 }
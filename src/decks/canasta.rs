@@ -2,10 +2,13 @@ use crate::decks::french::French;
 use crate::decks::modern::Modern;
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
+use crate::types::deck_builder::DeckBuilder;
+use crate::types::ordering::ByWeight;
 use crate::types::pile::Pile;
 use crate::types::rank::Rank;
 use crate::types::suit::Suit;
-use crate::types::traits::{Decked, Ranked, Suited};
+use crate::types::traits::{CardOrdering, Decked, Encoded, Literal, Ranked, Suited};
+use std::cmp::Ordering;
 use std::str::FromStr;
 
 /// [Canasta](https://en.wikipedia.org/wiki/Canasta)deck
@@ -73,15 +76,13 @@ impl Canasta {
 
     /// # Errors
     ///
-    /// Returns a `CardError` if the index is out of bounds.
-    ///
-    /// TODO: Add deck validation
+    /// Returns a `CardError` if the index is out of bounds, or if the cards aren't valid
+    /// for a `Canasta` deck.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(index: &str) -> Result<Pile<Modern, Modern>, CardError> {
-        match Pile::<Modern, Modern>::from_str(index) {
-            Ok(pile) => Ok(Canasta::wash(&pile).sort()),
-            Err(e) => Err(e),
-        }
+        let pile = Canasta::wash(&Pile::<Modern, Modern>::from_str(index)?).sort();
+        Canasta::validate(&pile)?;
+        Ok(pile)
     }
 
     /// Bumps up the weight of red threes and twos so that they sort correctly.
@@ -122,14 +123,14 @@ impl Canasta {
         Rank<RankType>: PartialEq<Rank<Modern>>,
         Suit<SuitType>: PartialEq<Suit<Modern>>,
     {
-        match card.index.as_str() {
+        match card.index {
             "3H" => Canasta::three_of_hearts(),
             "3D" => Canasta::three_of_diamonds(),
             "2S" => Canasta::two_of_spades(),
             "2H" => Canasta::two_of_hearts(),
             "2D" => Canasta::two_of_diamonds(),
             "2C" => Canasta::two_of_clubs(),
-            _ => card.clone(),
+            _ => *card,
         }
     }
 
@@ -139,11 +140,43 @@ impl Canasta {
     }
 }
 
+/// Sorts cards the way that they're sorted in a `Canasta` deck, with the red threes and the wild
+/// cards first, even if they haven't been reweighted.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let pile = Modern::from_str("AS 2C BJ 3H").unwrap();
+///
+/// assert_eq!(pile.sort().to_string(), "B🃟 A♠ 3♥ 2♣");
+/// assert_eq!(pile.sort_by_ordering(&Canasta {}).to_string(), "3♥ B🃟 2♣ A♠");
+/// ```
+impl CardOrdering<Modern, Modern> for Canasta {
+    fn compare(&self, a: &Card<Modern, Modern>, b: &Card<Modern, Modern>) -> Ordering {
+        let a = Canasta::bump::<Modern, Modern>(a);
+        let b = Canasta::bump::<Modern, Modern>(b);
+        ByWeight.compare(&a, &b)
+    }
+}
+
 impl Decked<Modern, Modern> for Canasta {
     fn deck() -> Pile<Modern, Modern> {
-        let deck = Modern::decks(2);
-
-        Canasta::wash(&deck).sort()
+        [
+            Canasta::three_of_hearts(),
+            Canasta::three_of_diamonds(),
+            Canasta::two_of_spades(),
+            Canasta::two_of_hearts(),
+            Canasta::two_of_diamonds(),
+            Canasta::two_of_clubs(),
+        ]
+        .into_iter()
+        .fold(
+            DeckBuilder::from_decked::<Modern>().copies(2),
+            |builder, card| builder.reweight(card, card.weight),
+        )
+        .build()
+        .map(|deck| Pile::from(deck).sort())
+        .expect("the Canasta deck is built from a Modern deck")
     }
 
     fn blank() -> Card<Modern, Modern> {
@@ -155,6 +188,18 @@ impl Decked<Modern, Modern> for Canasta {
     }
 }
 
+impl Encoded<Modern, Modern> for Canasta {}
+
+impl Literal for Canasta {
+    type RankType = Modern;
+    type SuitType = Modern;
+
+    const RANK_CHARS: &'static [char] = Modern::RANK_CHARS;
+    const SUIT_CHARS: &'static [char] = Modern::SUIT_CHARS;
+    const SPECIAL_RANK_CHARS: &'static [char] = Modern::SPECIAL_RANK_CHARS;
+    const SPECIAL_SUIT_CHARS: &'static [char] = Modern::SPECIAL_SUIT_CHARS;
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod decks__canasta__tests {
@@ -184,7 +229,7 @@ mod decks__canasta__tests {
     /// This doesn't work because of the way the red threes are being processed from
     /// `Decked::deck()`
     ///
-    /// UPDATE: wash() fixes it
+    /// UPDATE: `wash()` fixes it
     #[test]
     fn to_string__from_str() {
         let deck = Canasta::deck();
@@ -193,4 +238,24 @@ mod decks__canasta__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn card_ordering() {
+        let deck = Canasta::deck();
+        let unwashed = Modern::decks(2);
+
+        assert_eq!(
+            unwashed.sort_by_ordering(&Canasta {}).to_string(),
+            deck.to_string()
+        );
+        assert!(unwashed.same(&deck));
+    }
+
+    #[test]
+    fn from_str__invalid() {
+        assert_eq!(
+            Canasta::from_str("3H 3H 3H").unwrap_err(),
+            CardError::TooManyCopies("3H".to_string())
+        );
+    }
 }
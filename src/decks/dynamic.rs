@@ -0,0 +1,888 @@
+use crate::localization::{FluentName, Named};
+use crate::types::card::Card;
+use crate::types::card_error::CardError;
+use crate::types::deck_builder::{CustomDeck, DeckBuilder};
+use crate::types::pile::Pile;
+use crate::types::rank::Rank;
+use crate::types::suit::Suit;
+use crate::types::traits::{Decked, Ranked, Suited};
+use colored::Color;
+use fluent_templates::LanguageIdentifier;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// The rank and suit type of the cards in a [`DynamicDeck`], with the `ID` that the deck was
+/// loaded with.
+///
+/// Since its ranks and suits are only known at runtime, each `DynamicDeck` registers them under
+/// its `ID` when it's loaded, and `Dynamic<ID>` reads them back from there. Decks with different
+/// ids have different card types, so their cards can't be mixed up, and a `Pile` of them can be
+/// parsed with `FromStr` like any other once the deck has been loaded.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Dynamic<const ID: usize = 0> {}
+
+impl<const ID: usize> Dynamic<ID> {
+    pub const DECK_NAME: &'static str = "Dynamic";
+
+    /// Runs `f` on what the deck with this `ID` registered, if it's been loaded.
+    fn registered<T>(f: impl FnOnce(&Registered) -> T) -> Option<T> {
+        registry()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&ID)
+            .filter(|registered| registered.loaded)
+            .map(f)
+    }
+}
+
+impl<const ID: usize> Decked<Dynamic<ID>, Dynamic<ID>> for Dynamic<ID> {
+    /// The deck that was loaded with this `ID`, or an empty `Pile` if there isn't one yet.
+    fn deck() -> Pile<Dynamic<ID>, Dynamic<ID>> {
+        Dynamic::<ID>::registered(|registered| {
+            registered
+                .cards
+                .iter()
+                .cycle()
+                .take(registered.cards.len() * registered.copies)
+                .map(|(rank, suit)| Card::new(Rank::new(rank), Suit::new(suit)))
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+
+    fn blank() -> Card<Dynamic<ID>, Dynamic<ID>> {
+        Card::<Dynamic<ID>, Dynamic<ID>>::default()
+    }
+
+    fn guide() -> Option<String> {
+        None
+    }
+}
+
+impl<const ID: usize> Ranked for Dynamic<ID> {
+    fn rank_chars() -> Vec<char> {
+        Dynamic::<ID>::registered(|registered| registered.ranks.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn rank_names() -> Vec<&'static str> {
+        Dynamic::<ID>::registered(|registered| registered.rank_names.clone()).unwrap_or_default()
+    }
+
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        Dynamic::<ID>::registered(|registered| registered.ranks.get(c).copied()).flatten()
+    }
+
+    fn type_name() -> &'static str {
+        Dynamic::<ID>::DECK_NAME
+    }
+}
+
+impl<const ID: usize> Suited for Dynamic<ID> {
+    fn colors() -> HashMap<char, Color> {
+        Dynamic::<ID>::registered(|registered| registered.colors.clone()).unwrap_or_default()
+    }
+
+    fn suit_chars() -> Vec<char> {
+        Dynamic::<ID>::registered(|registered| registered.suits.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn suit_names() -> Vec<&'static str> {
+        Dynamic::<ID>::registered(|registered| registered.suit_names.clone()).unwrap_or_default()
+    }
+
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        Dynamic::<ID>::registered(|registered| registered.suits.get(c).copied()).flatten()
+    }
+
+    fn type_name() -> &'static str {
+        Dynamic::<ID>::DECK_NAME
+    }
+}
+
+/// What a [`DynamicDeck`] registered under its id when it was loaded.
+#[derive(Clone, Debug, Default)]
+struct Registered {
+    definition: DeckDefinition,
+    rank_names: Vec<&'static str>,
+    suit_names: Vec<&'static str>,
+    /// The rank names by the chars that they can be written with.
+    ranks: HashMap<char, &'static str>,
+    /// The suit names by the chars that they can be written with, including their symbols.
+    suits: HashMap<char, &'static str>,
+    colors: HashMap<char, Color>,
+    /// The rank and suit names of each card in one copy of the deck.
+    cards: Vec<(&'static str, &'static str)>,
+    copies: usize,
+    /// Whether its values have been added to the localization. Until then, the `ID` is taken,
+    /// but `Dynamic<ID>` acts as if the deck hasn't been loaded.
+    loaded: bool,
+}
+
+/// The decks that have been loaded, by id.
+fn registry() -> &'static RwLock<HashMap<usize, Registered>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<usize, Registered>>> = OnceLock::new();
+    REGISTRY.get_or_init(RwLock::default)
+}
+
+/// The description of a [`DynamicDeck`]. With the `toml` feature, it can be loaded from a TOML
+/// file with `DynamicDeck::from_toml()`.
+///
+/// Ranks and suits are listed from highest to lowest. Unless they're given a `weight`, ranks are
+/// weighted from `0` for the lowest one up, and suits from `1` up, the same as the built in
+/// decks.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "toml",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct DeckDefinition {
+    pub name: String,
+    /// How many copies of the whole deck there are. Defaults to `1`.
+    pub copies: Option<usize>,
+    pub ranks: Vec<RankDefinition>,
+    pub suits: Vec<SuitDefinition>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "toml",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct RankDefinition {
+    /// The [`FluentName`] of the rank.
+    pub name: String,
+    /// The index char of the rank, such as `A` for an ace.
+    pub index: String,
+    pub weight: Option<u32>,
+    pub prime: Option<u32>,
+    /// How many copies of the rank there are in each suit. Defaults to `1`.
+    pub copies: Option<usize>,
+    /// The long names of the rank, by language, such as `en-US = "Ace"`.
+    #[cfg_attr(feature = "toml", serde(default))]
+    pub long: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "toml",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct SuitDefinition {
+    /// The [`FluentName`] of the suit.
+    pub name: String,
+    /// The index char of the suit, such as `S` for spades.
+    pub index: String,
+    pub symbol: String,
+    /// The name of the suit's color, such as `red`.
+    pub color: Option<String>,
+    pub weight: Option<u32>,
+    /// The long names of the suit, by language, such as `en-US = "Spades"`.
+    #[cfg_attr(feature = "toml", serde(default))]
+    pub long: HashMap<String, String>,
+}
+
+/// A deck that's defined at runtime from a [`DeckDefinition`] instead of with its own
+/// [`Ranked`], [`Suited`] and [`Decked`] types.
+///
+/// The names, indexes and symbols of the ranks and suits are added to the
+/// [localization](crate::localization), so its cards display like the built in ones, and its
+/// piles sort, shuffle and validate the same way. The rest of the deck is registered under its
+/// `ID`, so that [`Dynamic<ID>`](Dynamic) can be used like the built in decks once it's loaded.
+/// Each deck needs an `ID` of its own. Loading a different deck with an `ID` that's already
+/// taken, or giving a rank or suit name a different index, weight or symbol than a deck that's
+/// already loaded gave it, is an error.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::decks::dynamic::{DeckDefinition, Dynamic, RankDefinition, SuitDefinition};
+///
+/// let rank = |name: &str, index: &str| RankDefinition {
+///     name: name.to_string(),
+///     index: index.to_string(),
+///     ..RankDefinition::default()
+/// };
+/// let suit = |name: &str, index: &str, symbol: &str| SuitDefinition {
+///     name: name.to_string(),
+///     index: index.to_string(),
+///     symbol: symbol.to_string(),
+///     ..SuitDefinition::default()
+/// };
+/// let definition = DeckDefinition {
+///     name: "Starlight".to_string(),
+///     copies: Some(2),
+///     ranks: vec![rank("starlight-sun", "U"), rank("starlight-moon", "O")],
+///     suits: vec![suit("starlight-gold", "G", "★"), suit("starlight-silver", "V", "☆")],
+/// };
+///
+/// let deck = DynamicDeck::<1>::new(&definition).unwrap();
+/// let pile = deck.from_str("OV UG O☆").unwrap();
+///
+/// assert_eq!(deck.deck().len(), 8);
+/// assert_eq!(deck.deck().to_string(), "U★ O★ U☆ O☆ U★ O★ U☆ O☆");
+/// assert_eq!(pile.sort().to_string(), "U★ O☆ O☆");
+/// assert!(deck.from_str("OV OV OV").is_err());
+///
+/// // Once it's loaded, its cards can be used like any other deck's.
+/// assert_eq!(Pile::<Dynamic<1>, Dynamic<1>>::from_str("OV UG O☆").unwrap(), pile);
+/// assert_eq!(Dynamic::<1>::deck(), deck.deck());
+///
+/// // Its id is taken now.
+/// let mut other = definition.clone();
+/// other.name = "Moonlight".to_string();
+/// assert!(DynamicDeck::<1>::new(&other).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DynamicDeck<const ID: usize = 0> {
+    name: String,
+    deck: CustomDeck<Dynamic<ID>, Dynamic<ID>>,
+}
+
+impl<const ID: usize> DynamicDeck<ID> {
+    /// Creates the deck, adding the names of its ranks and suits to the localization, and
+    /// registering it under its `ID`. Creating it again from the same definition returns the
+    /// same deck.
+    ///
+    /// The whole definition is checked before anything is added to the localization, so a
+    /// definition that's wrong leaves nothing behind, and can be fixed and loaded again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentName` if a rank or suit name isn't a valid
+    /// [`FluentName`], `CardError::InvalidDefinition` if something else about the definition is
+    /// wrong, such as an index that isn't a single char, a rank or suit that's listed twice or
+    /// shares an index with another one, a name that's already used by a built in deck or
+    /// another runtime deck, or an `ID` that's already taken by a different deck, or any of the
+    /// errors from [`DeckBuilder::build()`].
+    pub fn new(definition: &DeckDefinition) -> Result<Self, CardError> {
+        DynamicDeck::<ID>::check_id(definition)?;
+        let values = DynamicDeck::<ID>::values(definition)?;
+        let registered = DynamicDeck::<ID>::registered(definition)?;
+        let cards = registered.cards.clone();
+
+        {
+            let mut registry = registry().write().unwrap_or_else(PoisonError::into_inner);
+            let registered = registry.entry(ID).or_insert(registered);
+            if registered.definition != *definition {
+                return Err(DynamicDeck::<ID>::taken(&registered.definition));
+            }
+        }
+
+        let deck = DynamicDeck::<ID>::define(&values, &cards, definition);
+        let mut registry = registry().write().unwrap_or_else(PoisonError::into_inner);
+        match deck {
+            Ok(deck) => {
+                if let Some(registered) = registry.get_mut(&ID) {
+                    registered.loaded = true;
+                }
+                Ok(DynamicDeck {
+                    name: definition.name.clone(),
+                    deck,
+                })
+            }
+            Err(e) => {
+                if registry
+                    .get(&ID)
+                    .is_some_and(|registered| !registered.loaded)
+                {
+                    registry.remove(&ID);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Loads the deck from a TOML [`DeckDefinition`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let deck = DynamicDeck::<2>::from_toml(r#"
+    ///     name = "Coins"
+    ///
+    ///     [[ranks]]
+    ///     name = "coins-heads"
+    ///     index = "H"
+    ///     long = { en-US = "Heads", de = "Kopf" }
+    ///
+    ///     [[ranks]]
+    ///     name = "coins-tails"
+    ///     index = "T"
+    ///     long = { en-US = "Tails", de = "Zahl" }
+    ///
+    ///     [[suits]]
+    ///     name = "coins-copper"
+    ///     index = "C"
+    ///     symbol = "¢"
+    ///     color = "yellow"
+    ///     long = { en-US = "Copper", de = "Kupfer" }
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(deck.name(), "Coins");
+    /// assert_eq!(deck.deck().to_string(), "H¢ T¢");
+    /// assert_eq!(deck.from_str("TC").unwrap()[0].long(&FluentName::DEUTSCH), "Zahl Kupfer");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidDefinition` if the TOML isn't a valid `DeckDefinition`, or any
+    /// of the errors from [`DynamicDeck::new()`].
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, CardError> {
+        let definition: DeckDefinition =
+            toml::from_str(s).map_err(|e| CardError::InvalidDefinition(e.message().to_string()))?;
+        DynamicDeck::new(&definition)
+    }
+
+    /// Returns a fresh copy of the deck.
+    #[must_use]
+    pub fn deck(&self) -> Pile<Dynamic<ID>, Dynamic<ID>> {
+        self.deck.deck()
+    }
+
+    /// Parses the indexes, or the displayed cards, into a `Pile` of the deck's cards. See
+    /// [`CustomDeck::from_str()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CardError` if a card isn't in the deck, or if there are too many copies of it.
+    pub fn from_str(&self, index: &str) -> Result<Pile<Dynamic<ID>, Dynamic<ID>>, CardError> {
+        self.deck.from_str(index)
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Validates that the `Pile` could have been dealt from the deck.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotInDeck` or `CardError::TooManyCopies` like
+    /// [`Decked::validate()`].
+    pub fn validate(&self, pile: &Pile<Dynamic<ID>, Dynamic<ID>>) -> Result<(), CardError> {
+        self.deck.validate(pile)
+    }
+
+    /// Returns an error if a different deck has already been registered under the `ID`.
+    fn check_id(definition: &DeckDefinition) -> Result<(), CardError> {
+        let existing = registry()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&ID)
+            .map(|registered| registered.definition.clone());
+        match existing {
+            Some(existing) if existing != *definition => Err(DynamicDeck::<ID>::taken(&existing)),
+            _ => Ok(()),
+        }
+    }
+
+    fn taken(existing: &DeckDefinition) -> CardError {
+        CardError::InvalidDefinition(format!("id {ID} is already used by `{}`", existing.name))
+    }
+
+    /// Every value that the deck adds to the localization, after checking that none of them
+    /// clash with each other, or with the ones that are already there, and that no rank or suit
+    /// is listed twice or shares an index with another one.
+    fn values(definition: &DeckDefinition) -> Result<Vec<Value>, CardError> {
+        check_unique(
+            definition
+                .ranks
+                .iter()
+                .map(|rank| (rank.name.as_str(), rank.index.as_str())),
+        )?;
+        check_unique(
+            definition
+                .suits
+                .iter()
+                .map(|suit| (suit.name.as_str(), suit.index.as_str())),
+        )?;
+
+        let mut values = vec![];
+        for (rank, weight) in definition
+            .ranks
+            .iter()
+            .zip((0..definition.ranks.len()).rev())
+        {
+            values.extend(rank.values(weight)?);
+        }
+        for (suit, weight) in definition
+            .suits
+            .iter()
+            .zip((1..=definition.suits.len()).rev())
+        {
+            values.extend(suit.values(weight)?);
+        }
+
+        let mut seen: HashMap<(&str, &str, &LanguageIdentifier), &str> = HashMap::new();
+        for (name, section, lid, value) in &values {
+            FluentName::check_definition(name, section, lid, value)?;
+            match seen.insert((name, section, lid), value) {
+                Some(other) if other != value => {
+                    return Err(CardError::InvalidDefinition(format!(
+                        "`{name}-{section}` is both `{other}` and `{value}` for {lid}"
+                    )));
+                }
+                _ => {}
+            }
+        }
+        Ok(values)
+    }
+
+    /// What the deck registers under its `ID`, once its definition has been checked.
+    fn registered(definition: &DeckDefinition) -> Result<Registered, CardError> {
+        let mut registered = Registered {
+            definition: definition.clone(),
+            copies: definition.copies.unwrap_or(1),
+            ..Registered::default()
+        };
+        let mut ranks = vec![];
+        for rank in &definition.ranks {
+            let name = FluentName::declare(&rank.name)?.fluent_name_string();
+            registered.rank_names.push(name);
+            add_chars(
+                &mut registered.ranks,
+                index_char(&rank.name, &rank.index)?,
+                name,
+            );
+            ranks.push((name, rank.copies.unwrap_or(1)));
+        }
+        for suit in &definition.suits {
+            let name = FluentName::declare(&suit.name)?.fluent_name_string();
+            let index = index_char(&suit.name, &suit.index)?;
+            registered.suit_names.push(name);
+            add_chars(&mut registered.suits, index, name);
+            if let [symbol] = suit.symbol.chars().collect::<Vec<_>>()[..] {
+                registered.suits.entry(symbol).or_insert(name);
+            }
+            if let Some(color) = &suit.color {
+                registered.colors.insert(index, color_named(color)?);
+            }
+        }
+        for suit in &registered.suit_names {
+            for (rank, copies) in &ranks {
+                for _ in 0..*copies {
+                    registered.cards.push((*rank, *suit));
+                }
+            }
+        }
+        Ok(registered)
+    }
+
+    /// Adds the values to the localization, and builds the deck from them.
+    fn define(
+        values: &[Value],
+        cards: &[(&'static str, &'static str)],
+        definition: &DeckDefinition,
+    ) -> Result<CustomDeck<Dynamic<ID>, Dynamic<ID>>, CardError> {
+        for (name, section, lid, value) in values {
+            FluentName::declare(name)?.define(section, lid, value)?;
+        }
+
+        let pile: Pile<Dynamic<ID>, Dynamic<ID>> = cards
+            .iter()
+            .map(|(rank, suit)| Card::new(Rank::new(rank), Suit::new(suit)))
+            .collect();
+        DeckBuilder::new(pile)
+            .copies(definition.copies.unwrap_or(1))
+            .build()
+    }
+}
+
+/// A value that a [`DynamicDeck`] adds to the localization: the name, its section, the
+/// language, and the value.
+type Value = (String, &'static str, LanguageIdentifier, String);
+
+impl RankDefinition {
+    /// The rank's values, weighted by its place in the deck unless it has a `weight`.
+    fn values(&self, weight: usize) -> Result<Vec<Value>, CardError> {
+        let weight = match self.weight {
+            Some(weight) => weight,
+            None => u32::try_from(weight).map_err(|_| too_many(&self.name))?,
+        };
+        let mut values = name_values(&self.name, &self.index, &self.long)?;
+        values.push(us_value(
+            &self.name,
+            FluentName::FLUENT_WEIGHT_SECTION,
+            weight.to_string(),
+        ));
+        if let Some(prime) = self.prime {
+            values.push(us_value(
+                &self.name,
+                FluentName::FLUENT_PRIME_SECTION,
+                prime.to_string(),
+            ));
+        }
+        Ok(values)
+    }
+}
+
+impl SuitDefinition {
+    /// The suit's values, weighted by its place in the deck unless it has a `weight`.
+    fn values(&self, weight: usize) -> Result<Vec<Value>, CardError> {
+        let weight = match self.weight {
+            Some(weight) => weight,
+            None => u32::try_from(weight).map_err(|_| too_many(&self.name))?,
+        };
+        if let Some(color) = &self.color {
+            color_named(color)?;
+        }
+        let mut values = name_values(&self.name, &self.index, &self.long)?;
+        values.push(us_value(
+            &self.name,
+            FluentName::FLUENT_SYMBOL_SECTION,
+            self.symbol.clone(),
+        ));
+        values.push(us_value(
+            &self.name,
+            FluentName::FLUENT_WEIGHT_SECTION,
+            weight.to_string(),
+        ));
+        Ok(values)
+    }
+}
+
+/// The index and long names of a rank or suit.
+fn name_values(
+    name: &str,
+    index: &str,
+    long: &HashMap<String, String>,
+) -> Result<Vec<Value>, CardError> {
+    index_char(name, index)?;
+    let mut values = vec![us_value(
+        name,
+        FluentName::FLUENT_INDEX_SECTION,
+        index.to_string(),
+    )];
+    for (lid, value) in long {
+        let lid = LanguageIdentifier::from_str(lid)
+            .map_err(|_| CardError::InvalidDefinition(format!("`{lid}` isn't a language")))?;
+        values.push((
+            name.to_string(),
+            FluentName::FLUENT_LONG_SECTION,
+            lid,
+            value.clone(),
+        ));
+    }
+    Ok(values)
+}
+
+fn us_value(name: &str, section: &'static str, value: String) -> Value {
+    (name.to_string(), section, FluentName::US_ENGLISH, value)
+}
+
+fn index_char(name: &str, index: &str) -> Result<char, CardError> {
+    let [c] = index.chars().collect::<Vec<_>>()[..] else {
+        return Err(CardError::InvalidDefinition(format!(
+            "`{name}` needs a single char index, not `{index}`"
+        )));
+    };
+    Ok(c)
+}
+
+fn color_named(color: &str) -> Result<Color, CardError> {
+    Color::from_str(color)
+        .map_err(|()| CardError::InvalidDefinition(format!("`{color}` isn't a color")))
+}
+
+/// Makes sure that none of the names is listed twice, and that none of the indexes is used
+/// twice, ignoring case.
+fn check_unique<'a>(names: impl Iterator<Item = (&'a str, &'a str)>) -> Result<(), CardError> {
+    let mut names_seen = HashSet::new();
+    let mut indexes: HashMap<String, &str> = HashMap::new();
+    for (name, index) in names {
+        if !names_seen.insert(name) {
+            return Err(CardError::InvalidDefinition(format!(
+                "`{name}` is listed twice"
+            )));
+        }
+        if let Some(other) = indexes.insert(index.to_lowercase(), name) {
+            return Err(CardError::InvalidDefinition(format!(
+                "`{other}` and `{name}` have the same index `{index}`"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Maps the index char, and the same char in the other case, to the name, so that indexes can
+/// be parsed without worrying about case, like the built in decks.
+fn add_chars(chars: &mut HashMap<char, &'static str>, index: char, name: &'static str) {
+    chars.insert(index, name);
+    for c in index.to_lowercase().chain(index.to_uppercase()) {
+        chars.entry(c).or_insert(name);
+    }
+}
+
+fn too_many(name: &str) -> CardError {
+    CardError::InvalidDefinition(format!("too many ranks or suits at `{name}`"))
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod decks__dynamic__tests {
+    use super::*;
+    use colored::Colorize;
+
+    fn rank(name: &str, index: &str) -> RankDefinition {
+        RankDefinition {
+            name: name.to_string(),
+            index: index.to_string(),
+            ..RankDefinition::default()
+        }
+    }
+
+    fn suit(name: &str, index: &str, symbol: &str) -> SuitDefinition {
+        SuitDefinition {
+            name: name.to_string(),
+            index: index.to_string(),
+            symbol: symbol.to_string(),
+            ..SuitDefinition::default()
+        }
+    }
+
+    fn definition() -> DeckDefinition {
+        DeckDefinition {
+            name: "Test".to_string(),
+            copies: None,
+            ranks: vec![
+                rank("dynamic-test-high", "Y"),
+                RankDefinition {
+                    copies: Some(2),
+                    ..rank("dynamic-test-low", "Z")
+                },
+            ],
+            suits: vec![
+                SuitDefinition {
+                    color: Some("red".to_string()),
+                    ..suit("dynamic-test-red", "R", "◆")
+                },
+                suit("dynamic-test-black", "K", "◇"),
+            ],
+        }
+    }
+
+    /// The id that the tests load [`definition()`] under.
+    type TestDeck = DynamicDeck<100>;
+
+    #[test]
+    fn new() {
+        let deck = TestDeck::new(&definition()).unwrap();
+
+        assert_eq!(deck.name(), "Test");
+        assert_eq!(deck.deck().to_string(), "Y◆ Z◆ Z◆ Y◇ Z◇ Z◇");
+        assert_eq!(deck.deck().index(), "YR ZR ZR YK ZK ZK");
+        assert_eq!(Dynamic::<100>::deck(), deck.deck());
+        assert_eq!(TestDeck::new(&definition()).unwrap(), deck);
+    }
+
+    #[test]
+    fn new__weights() {
+        let deck = TestDeck::new(&definition()).unwrap();
+        let weights: Vec<u32> = deck.deck().iter().map(|card| card.weight).collect();
+
+        assert_eq!(weights, vec![2001, 2000, 2000, 1001, 1000, 1000]);
+        assert_eq!(
+            deck.deck().shuffle_seeded(7).sort().to_string(),
+            deck.deck().to_string()
+        );
+    }
+
+    #[test]
+    fn new__color() {
+        let deck = TestDeck::new(&definition()).unwrap();
+
+        assert_eq!(Dynamic::<100>::colors().get(&'R'), Some(&Color::Red));
+        assert!(Dynamic::<101>::colors().is_empty());
+        assert_eq!(
+            deck.from_str("YR").unwrap().to_color_symbol_string(),
+            "Y◆".red().to_string()
+        );
+    }
+
+    #[test]
+    fn new__built_in_conflict() {
+        let definition = DeckDefinition {
+            ranks: vec![rank("dynamic-test-pirate", "P")],
+            suits: vec![suit("spades", "S", "☠")],
+            ..DeckDefinition::default()
+        };
+
+        assert_eq!(
+            DynamicDeck::<101>::new(&definition),
+            Err(CardError::InvalidDefinition(
+                "`spades-symbol` is already `♠` for en-US".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new__runtime_conflict() {
+        TestDeck::new(&definition()).unwrap();
+        let mut definition = definition();
+        definition.ranks[0].index = "X".to_string();
+
+        assert_eq!(
+            DynamicDeck::<102>::new(&definition),
+            Err(CardError::InvalidDefinition(
+                "`dynamic-test-high-index` is already `Y` for en-US".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new__id_taken() {
+        TestDeck::new(&definition()).unwrap();
+        let mut definition = definition();
+        definition.name = "Other".to_string();
+
+        assert_eq!(
+            TestDeck::new(&definition),
+            Err(CardError::InvalidDefinition(
+                "id 100 is already used by `Test`".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn new__invalid() {
+        let wide_index = DeckDefinition {
+            ranks: vec![rank("dynamic-test-wide", "WW")],
+            suits: vec![suit("dynamic-test-narrow", "N", "|")],
+            ..DeckDefinition::default()
+        };
+        let missing_index = DeckDefinition {
+            ranks: vec![rank("dynamic-test-none", "")],
+            ..wide_index.clone()
+        };
+        let mut bad_color = definition();
+        bad_color.suits[1].color = Some("plaid".to_string());
+
+        assert_eq!(
+            DynamicDeck::<103>::new(&wide_index),
+            Err(CardError::InvalidDefinition(
+                "`dynamic-test-wide` needs a single char index, not `WW`".to_string()
+            ))
+        );
+        assert_eq!(
+            DynamicDeck::<103>::new(&missing_index),
+            Err(CardError::InvalidDefinition(
+                "`dynamic-test-none` needs a single char index, not ``".to_string()
+            ))
+        );
+        assert_eq!(
+            DynamicDeck::<103>::new(&bad_color),
+            Err(CardError::InvalidDefinition(
+                "`plaid` isn't a color".to_string()
+            ))
+        );
+        assert_eq!(
+            DynamicDeck::<103>::new(&DeckDefinition::default()),
+            Err(CardError::InvalidCardCount(0))
+        );
+        assert!(Dynamic::<103>::deck().is_empty());
+    }
+
+    #[test]
+    fn new__retry_after_failure() {
+        let suits = vec![suit("dynamic-test-retry-suit", "Q", "♒")];
+        let failed = DeckDefinition {
+            name: "Retry".to_string(),
+            ranks: vec![
+                rank("dynamic-test-retry-high", "H"),
+                rank("dynamic-test-retry-low", "LL"),
+            ],
+            suits: suits.clone(),
+            ..DeckDefinition::default()
+        };
+        let fixed = DeckDefinition {
+            ranks: vec![rank("dynamic-test-retry-high", "H")],
+            ..failed.clone()
+        };
+
+        assert!(DynamicDeck::<104>::new(&failed).is_err());
+        assert!(Dynamic::<104>::deck().is_empty());
+
+        let deck = DynamicDeck::<104>::new(&fixed).unwrap();
+
+        assert_eq!(deck.deck().index(), "HQ");
+        assert_eq!(deck.deck()[0].rank.weight, 0);
+        assert_eq!(Dynamic::<104>::deck(), deck.deck());
+    }
+
+    #[test]
+    fn new__duplicates() {
+        let twice = DeckDefinition {
+            ranks: vec![
+                rank("dynamic-test-twice", "W"),
+                rank("dynamic-test-twice", "W"),
+            ],
+            suits: vec![suit("dynamic-test-once", "O", "○")],
+            ..DeckDefinition::default()
+        };
+        let same_index = DeckDefinition {
+            ranks: vec![
+                rank("dynamic-test-upper", "U"),
+                rank("dynamic-test-lower", "u"),
+            ],
+            ..twice.clone()
+        };
+
+        assert_eq!(
+            DynamicDeck::<105>::new(&twice),
+            Err(CardError::InvalidDefinition(
+                "`dynamic-test-twice` is listed twice".to_string()
+            ))
+        );
+        assert_eq!(
+            DynamicDeck::<105>::new(&same_index),
+            Err(CardError::InvalidDefinition(
+                "`dynamic-test-upper` and `dynamic-test-lower` have the same index `u`".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let deck = TestDeck::new(&definition()).unwrap();
+        let pile = deck.from_str("Z◇ ZK YR").unwrap();
+
+        assert_eq!(pile.to_string(), "Z◇ Z◇ Y◆");
+        assert!(deck.validate(&pile).is_ok());
+        assert_eq!(
+            deck.from_str("YK YK").unwrap_err(),
+            CardError::TooManyCopies("YK".to_string())
+        );
+        assert!(deck.from_str("AS").is_err());
+    }
+
+    #[test]
+    fn pile__from_str() {
+        TestDeck::new(&definition()).unwrap();
+
+        let pile = Pile::<Dynamic<100>, Dynamic<100>>::from_str("Z◇ zk YR").unwrap();
+
+        assert_eq!(pile.to_string(), "Z◇ Z◇ Y◆");
+        assert!(Dynamic::<100>::validate(&pile).is_ok());
+        assert!(Pile::<Dynamic<104>, Dynamic<104>>::from_str("YR").is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml__invalid() {
+        assert!(matches!(
+            TestDeck::from_toml("name = 5"),
+            Err(CardError::InvalidDefinition(_))
+        ));
+        assert!(matches!(
+            TestDeck::from_toml("name = \"x\"\nranks = []\nsuits = []\nextra = 1"),
+            Err(CardError::InvalidDefinition(_))
+        ));
+    }
+}
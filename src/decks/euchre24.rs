@@ -2,7 +2,7 @@ use crate::decks::french::French;
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
-use crate::types::traits::{Decked, Ranked};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked};
 use std::str::FromStr;
 
 /// This deck represents the most common 24 card form of
@@ -38,9 +38,20 @@ impl Decked<Euchre24, French> for Euchre24 {
     }
 }
 
+impl Encoded<Euchre24, French> for Euchre24 {}
+
+impl Literal for Euchre24 {
+    type RankType = Euchre24;
+    type SuitType = French;
+
+    const RANK_CHARS: &'static [char] =
+        &['9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k', 'A', 'a'];
+    const SUIT_CHARS: &'static [char] = French::SUIT_CHARS;
+}
+
 impl Ranked for Euchre24 {
     fn rank_chars() -> Vec<char> {
-        vec!['9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k', 'A', 'a']
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
@@ -54,6 +65,10 @@ impl Ranked for Euchre24 {
         ]
     }
 
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        French::rank_for_char(c)
+    }
+
     fn type_name() -> &'static str {
         Euchre24::DECK_NAME
     }
@@ -171,4 +186,16 @@ mod decks__euchre__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = Euchre24::distinct();
+
+        assert_eq!(distinct.len(), 24);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(Euchre24::ordinal(card), Some(i));
+            assert_eq!(Euchre24::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(Euchre24::from_ordinal(24), None);
+    }
 }
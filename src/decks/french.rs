@@ -1,7 +1,7 @@
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
-use crate::types::traits::{Decked, Ranked, Suited};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked, Suited};
 use crate::types::utils::Bit;
 use colored::Color;
 use std::collections::HashMap;
@@ -10,6 +10,10 @@ use std::str::FromStr;
 /// These macros make me very happy. They plaster over a lot of headaches
 /// from the generics.
 ///
+/// `card!(Tarot: "🤡M")` takes a card from any deck that implements
+/// [`Literal`](crate::types::traits::Literal), and fails to compile if the index isn't valid for
+/// it. See [`pile!`](crate::pile).
+///
 /// ```
 /// use cardpack::prelude::*;
 ///
@@ -371,6 +375,25 @@ macro_rules! card {
     (__) => {
         Card::<French, French>::default()
     };
+    ($deck:ident : $index:literal) => {{
+        const _: () = ::core::assert!(
+            $crate::types::utils::Literals::is_card_of::<$deck>($index),
+            ::core::concat!("invalid card index: ", $index),
+        );
+        let card = <$crate::types::card::Card<
+            <$deck as $crate::types::traits::Literal>::RankType,
+            <$deck as $crate::types::traits::Literal>::SuitType,
+        > as ::core::str::FromStr>::from_str($index)
+        .expect(::core::concat!("checked while compiling: ", $index));
+        ::core::assert!(
+            !card.is_blank(),
+            ::core::concat!(
+                "the deck's literal chars don't match its ranks and suits: ",
+                $index
+            ),
+        );
+        card
+    }};
     ($rank:expr, $suit:expr) => {
         Card::<French, French>::new(Rank::<French>::from($rank), Suit::<French>::from($suit))
     };
@@ -387,6 +410,77 @@ macro_rules! cards {
     };
 }
 
+/// A [`Pile`] of cards from any deck that implements [`Literal`], checked while it's being
+/// compiled. The [`card!`] macro does the same for a single card.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// assert_eq!(pile!(Skat: "DE KH").to_string(), "D♣ K♥");
+/// assert_eq!(pile!(Pinochle: "AS AS TD").to_string(), "A♠ A♠ T♦");
+/// assert_eq!(pile!(Tarot: "🤡M 🧙M AW"), Tarot::from_str("🤡M 🧙M AW").unwrap());
+/// assert_eq!(card!(Tarot: "🤡M"), Tarot::deck()[0]);
+/// assert_eq!(card!(Modern: "BJ"), Modern::big_joker());
+/// assert_eq!(card!(French: "AS"), card!(AS));
+/// ```
+///
+/// An index that isn't valid for the deck doesn't compile:
+///
+/// ```compile_fail
+/// use cardpack::prelude::*;
+///
+/// // `L` is Laub, a suit, not a rank.
+/// let pile = pile!(Skat: "DE LH");
+/// ```
+///
+/// ```compile_fail
+/// use cardpack::prelude::*;
+///
+/// let card = card!(French: "BJ");
+/// ```
+///
+/// Neither does a rank and a suit that are never in the same card, such as a joker rank in a
+/// regular suit:
+///
+/// ```compile_fail
+/// use cardpack::prelude::*;
+///
+/// let card = card!(Modern: "BS");
+/// ```
+///
+/// ```compile_fail
+/// use cardpack::prelude::*;
+///
+/// let pile = pile!(Tarot: "🤡M 🤡W");
+/// ```
+///
+/// # Panics
+///
+/// If a char in the deck's [`Literal`] consts doesn't map to one of its ranks or suits, so the
+/// index compiles but its cards would be blank.
+#[macro_export]
+macro_rules! pile {
+    ($deck:ident : $index:literal) => {{
+        const _: () = ::core::assert!(
+            $crate::types::utils::Literals::is_pile_of::<$deck>($index),
+            ::core::concat!("invalid pile index: ", $index),
+        );
+        let pile = <$crate::types::pile::Pile<
+            <$deck as $crate::types::traits::Literal>::RankType,
+            <$deck as $crate::types::traits::Literal>::SuitType,
+        > as ::core::str::FromStr>::from_str($index)
+        .expect(::core::concat!("checked while compiling: ", $index));
+        ::core::assert!(
+            pile.iter().all(|card| !card.is_blank()),
+            ::core::concat!(
+                "the deck's literal chars don't match its ranks and suits: ",
+                $index
+            ),
+        );
+        pile
+    }};
+}
+
 /// `French` is a
 /// [unit-like struct](https://doc.rust-lang.org/book/ch05-01-defining-structs.html#unit-like-structs-without-any-fields)
 /// that represents a deck made up of with [French suited playing cards](https://en.wikipedia.org/wiki/Standard_52-card_deck)
@@ -503,12 +597,24 @@ impl Decked<French, French> for French {
     }
 }
 
+impl Encoded<French, French> for French {}
+
+impl Literal for French {
+    type RankType = French;
+    type SuitType = French;
+
+    const RANK_CHARS: &'static [char] = &[
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k', 'A',
+        'a',
+    ];
+    const SUIT_CHARS: &'static [char] = &[
+        '♤', '♠', 'S', 's', '♡', '♥', 'H', 'h', '♢', '♦', 'D', 'd', '♧', '♣', 'C', 'c',
+    ];
+}
+
 impl Ranked for French {
     fn rank_chars() -> Vec<char> {
-        vec![
-            '2', '3', '4', '5', '6', '7', '8', '9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k',
-            'A', 'a',
-        ]
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
@@ -529,6 +635,25 @@ impl Ranked for French {
         ]
     }
 
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            '2' => Some(French::TWO),
+            '3' => Some(French::THREE),
+            '4' => Some(French::FOUR),
+            '5' => Some(French::FIVE),
+            '6' => Some(French::SIX),
+            '7' => Some(French::SEVEN),
+            '8' => Some(French::EIGHT),
+            '9' => Some(French::NINE),
+            'T' | 't' | '0' => Some(French::TEN),
+            'J' | 'j' => Some(French::JACK),
+            'Q' | 'q' => Some(French::QUEEN),
+            'K' | 'k' => Some(French::KING),
+            'A' | 'a' => Some(French::ACE),
+            _ => None,
+        }
+    }
+
     fn type_name() -> &'static str {
         French::DECK_NAME
     }
@@ -545,9 +670,7 @@ impl Suited for French {
     }
 
     fn suit_chars() -> Vec<char> {
-        vec![
-            '♤', '♠', 'S', 's', '♡', '♥', 'H', 'h', '♢', '♦', 'D', 'd', '♧', '♣', 'C', 'c',
-        ]
+        Self::SUIT_CHARS.to_vec()
     }
 
     fn suit_names() -> Vec<&'static str> {
@@ -559,6 +682,16 @@ impl Suited for French {
         ]
     }
 
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            'S' | 's' | '♤' | '♠' => Some(French::SPADES),
+            'H' | 'h' | '♡' | '♥' => Some(French::HEARTS),
+            'D' | 'd' | '♢' | '♦' => Some(French::DIAMONDS),
+            'C' | 'c' | '♧' | '♣' => Some(French::CLUBS),
+            _ => None,
+        }
+    }
+
     fn type_name() -> &'static str {
         French::DECK_NAME
     }
@@ -568,8 +701,6 @@ impl Suited for French {
 #[allow(non_snake_case)]
 mod decks__standard52__tests {
     use super::*;
-    use crate::card;
-    use crate::cards;
     use crate::localization::{FluentName, Named};
     use crate::types::card::Card;
     use crate::types::rank::Rank;
@@ -696,16 +827,15 @@ mod decks__standard52__tests {
     #[case("4c", CardNumber::FOUR_CLUBS)]
     #[case("3C", CardNumber::TREY_CLUBS)]
     #[case("2C", CardNumber::DEUCE_CLUBS)]
-    #[case("__", 0u32)]
     fn card__get_ckc_number(#[case] input: &str, #[case] expected_ckc: u32) {
-        assert_eq!(expected_ckc, card!(input).get_ckc_number());
+        assert_eq!(Some(expected_ckc), card!(input).get_ckc_number());
     }
 
     #[test]
     fn card__get_ckc_number__blank() {
         let blank = card!("__");
         assert!(blank.is_blank());
-        assert_eq!(0, blank.get_ckc_number());
+        assert_eq!(None, blank.get_ckc_number());
     }
 
     #[test]
@@ -935,4 +1065,16 @@ mod decks__standard52__tests {
         let expected = "xxxAKQJT 98765432 ♠♥♦♣rrrr xxpppppp\n10101010 10101010 10101010 10101010";
         assert_eq!(French::string_guided(ckc), expected);
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = French::distinct();
+
+        assert_eq!(distinct.len(), 52);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(French::ordinal(card), Some(i));
+            assert_eq!(French::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(French::from_ordinal(52), None);
+    }
 }
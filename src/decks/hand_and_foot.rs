@@ -2,7 +2,7 @@ use crate::decks::modern::Modern;
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
-use crate::types::traits::Decked;
+use crate::types::traits::{Decked, Encoded, Literal};
 use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -34,6 +34,18 @@ impl Decked<Modern, Modern> for HandAndFoot {
     }
 }
 
+impl Encoded<Modern, Modern> for HandAndFoot {}
+
+impl Literal for HandAndFoot {
+    type RankType = Modern;
+    type SuitType = Modern;
+
+    const RANK_CHARS: &'static [char] = Modern::RANK_CHARS;
+    const SUIT_CHARS: &'static [char] = Modern::SUIT_CHARS;
+    const SPECIAL_RANK_CHARS: &'static [char] = Modern::SPECIAL_RANK_CHARS;
+    const SPECIAL_SUIT_CHARS: &'static [char] = Modern::SPECIAL_SUIT_CHARS;
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod decks__hand_and_foot__tests {
@@ -65,4 +77,18 @@ mod decks__hand_and_foot__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__validate() {
+        let mut deck = HandAndFoot::deck();
+
+        assert!(HandAndFoot::validate(&deck).is_ok());
+
+        deck.push(Modern::big_joker());
+
+        assert_eq!(
+            HandAndFoot::validate(&deck).unwrap_err(),
+            CardError::TooManyCopies("BJ".to_string())
+        );
+    }
 }
@@ -1,4 +1,5 @@
 pub mod canasta;
+pub mod dynamic;
 pub mod euchre24;
 pub mod french;
 pub mod hand_and_foot;
@@ -10,6 +11,7 @@ pub mod spades;
 pub mod tarot;
 
 pub use crate::decks::canasta::Canasta;
+pub use crate::decks::dynamic::DynamicDeck;
 pub use crate::decks::euchre24::Euchre24;
 pub use crate::decks::french::French;
 pub use crate::decks::french::FrenchCard;
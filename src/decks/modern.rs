@@ -4,11 +4,13 @@ use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
 use crate::types::rank::Rank;
 use crate::types::suit::Suit;
-use crate::types::traits::{Decked, Ranked, Suited};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked, Suited};
 use colored::Color;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+/// Falls back to a blank card if the index isn't valid. `card!(Modern: "...")` checks the index
+/// when it's compiled instead.
 #[macro_export]
 #[allow(clippy::pedantic)]
 macro_rules! modern_card {
@@ -27,6 +29,26 @@ macro_rules! modern {
 
 /// `French` with Jokers.
 ///
+/// The jokers are the deck's [special ranks](Ranked::special_rank_names), `BIG` and `LITTLE`,
+/// in its [special suit](Suited::special_suit_names), `JOKER`.
+///
+/// **Breaking change in 0.6:** `Modern::rank_names()` and `Modern::suit_names()` used to
+/// include the jokers. They're now the same as [`French`]'s, and the jokers are only in
+/// `Modern::special_rank_names()` and `Modern::special_suit_names()`, so code that looks for
+/// them in the old lists needs to chain the special ones on:
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let ranks: Vec<_> = Modern::special_rank_names()
+///     .into_iter()
+///     .chain(Modern::rank_names())
+///     .collect();
+///
+/// assert_eq!(ranks.len(), 15);
+/// assert_eq!(ranks[..2], [Modern::BIG, Modern::LITTLE]);
+/// ```
+///
 /// <https://www.pagat.com/rummy/canasta.html#classic-threes>
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Modern {}
@@ -76,18 +98,6 @@ impl Modern {
 }
 
 impl Decked<Modern, Modern> for Modern {
-    fn deck() -> Pile<Modern, Modern> {
-        let mut deck = Modern::jokers();
-
-        // TODO: HACK
-        let raw52 = French::deck().to_string();
-        let base52 = Pile::<Modern, Modern>::from_str(&raw52).unwrap();
-
-        deck.extend(&base52);
-
-        deck
-    }
-
     fn blank() -> Card<Modern, Modern> {
         Card::<Modern, Modern>::default()
     }
@@ -97,32 +107,43 @@ impl Decked<Modern, Modern> for Modern {
     }
 }
 
+impl Encoded<Modern, Modern> for Modern {}
+
+impl Literal for Modern {
+    type RankType = Modern;
+    type SuitType = Modern;
+
+    const RANK_CHARS: &'static [char] = &[
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k', 'A',
+        'a', 'B', 'b', 'L', 'l',
+    ];
+    const SUIT_CHARS: &'static [char] = &[
+        '♤', '♠', 'S', 's', '♡', '♥', 'H', 'h', '♢', '♦', 'D', 'd', '♧', '♣', 'C', 'c', '🃟', 'T',
+        't', 'J', 'j',
+    ];
+    const SPECIAL_RANK_CHARS: &'static [char] = &['B', 'b', 'L', 'l'];
+    const SPECIAL_SUIT_CHARS: &'static [char] = &['🃟', 'T', 't', 'J', 'j'];
+}
+
 impl Ranked for Modern {
     fn rank_chars() -> Vec<char> {
-        vec![
-            '2', '3', '4', '5', '6', '7', '8', '9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k',
-            'A', 'a', 'B', 'b', 'L', 'l',
-        ]
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
-        vec![
-            Modern::BIG,
-            Modern::LITTLE,
-            French::ACE,
-            French::KING,
-            French::QUEEN,
-            French::JACK,
-            French::TEN,
-            French::NINE,
-            French::EIGHT,
-            French::SEVEN,
-            French::SIX,
-            French::FIVE,
-            French::FOUR,
-            French::THREE,
-            French::TWO,
-        ]
+        French::rank_names()
+    }
+
+    fn special_rank_names() -> Vec<&'static str> {
+        vec![Modern::BIG, Modern::LITTLE]
+    }
+
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            'B' | 'b' => Some(Modern::BIG),
+            'L' | 'l' => Some(Modern::LITTLE),
+            _ => French::rank_for_char(c),
+        }
     }
 
     fn type_name() -> &'static str {
@@ -142,20 +163,22 @@ impl Suited for Modern {
     }
 
     fn suit_chars() -> Vec<char> {
-        vec![
-            '♤', '♠', 'S', 's', '♡', '♥', 'H', 'h', '♢', '♦', 'D', 'd', '♧', '♣', 'C', 'c', '🃟',
-            'T', 't', 'J', 'j',
-        ]
+        Self::SUIT_CHARS.to_vec()
     }
 
     fn suit_names() -> Vec<&'static str> {
-        vec![
-            Modern::JOKER,
-            French::SPADES,
-            French::HEARTS,
-            French::DIAMONDS,
-            French::CLUBS,
-        ]
+        French::suit_names()
+    }
+
+    fn special_suit_names() -> Vec<&'static str> {
+        vec![Modern::JOKER]
+    }
+
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            '🃟' | 'J' | 'j' | 'T' | 't' => Some(Modern::JOKER),
+            _ => French::suit_for_char(c),
+        }
     }
 
     fn type_name() -> &'static str {
@@ -168,9 +191,8 @@ impl Suited for Modern {
 mod decks__modern__tests {
     use super::*;
     use crate::localization::{FluentName, Named};
-    use crate::modern;
-    use crate::modern_card;
     use crate::types::rank::Rank;
+    use crate::types::suit::Suit;
     use std::str::FromStr;
 
     #[test]
@@ -288,22 +310,44 @@ mod decks__modern__tests {
     fn ranked__names() {
         let names = Rank::<Modern>::rank_names();
 
-        assert_eq!(names.len(), 15);
-        assert_eq!(names[0], Modern::BIG);
-        assert_eq!(names[1], Modern::LITTLE);
-        assert_eq!(names[2], French::ACE);
-        assert_eq!(names[3], French::KING);
-        assert_eq!(names[4], French::QUEEN);
-        assert_eq!(names[5], French::JACK);
-        assert_eq!(names[6], French::TEN);
-        assert_eq!(names[7], French::NINE);
-        assert_eq!(names[8], French::EIGHT);
-        assert_eq!(names[9], French::SEVEN);
-        assert_eq!(names[10], French::SIX);
-        assert_eq!(names[11], French::FIVE);
-        assert_eq!(names[12], French::FOUR);
-        assert_eq!(names[13], French::THREE);
-        assert_eq!(names[14], French::TWO);
+        assert_eq!(names, French::rank_names());
+        assert_eq!(
+            Rank::<Modern>::special_rank_names(),
+            vec![Modern::BIG, Modern::LITTLE]
+        );
+    }
+
+    #[test]
+    fn sort__shuffled() {
+        let deck = Modern::deck();
+
+        assert_ne!(deck.shuffle_seeded(3), deck);
+        assert_eq!(deck.shuffle_seeded(3).sort(), deck);
+    }
+
+    #[test]
+    fn map_by_suit__jokers() {
+        let map = Modern::deck().shuffle_seeded(3).map_by_suit();
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(
+            map[&Suit::<Modern>::new(Modern::JOKER)].sort().to_string(),
+            "B🃟 L🃟"
+        );
+        assert_eq!(map[&Suit::<Modern>::new(French::SPADES)].len(), 13);
+    }
+
+    #[test]
+    fn deck__two_tiers() {
+        let mut deck = Modern::deck();
+        let by_suit = deck.map_by_suit();
+        let jokers = &by_suit[&Suit::<Modern>::new(Modern::JOKER)];
+
+        assert_eq!(deck.len(), 54);
+        assert_eq!(by_suit.len(), 5);
+        assert_eq!(jokers, &Modern::jokers());
+        assert!(jokers.v().iter().all(|card| card.suit.is_special()));
+        assert_eq!(deck.draw(2), Modern::jokers());
     }
 
     #[test]
@@ -325,4 +369,16 @@ mod decks__modern__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = Modern::distinct();
+
+        assert_eq!(distinct.len(), 54);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(Modern::ordinal(card), Some(i));
+            assert_eq!(Modern::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(Modern::from_ordinal(54), None);
+    }
 }
@@ -4,7 +4,7 @@ use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
 use crate::types::rank::Rank;
 use crate::types::suit::Suit;
-use crate::types::traits::{Decked, Ranked};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked};
 use std::str::FromStr;
 
 #[macro_export]
@@ -225,7 +225,6 @@ impl Decked<Pinochle, French> for Pinochle {
     /// assert_eq!(deck.to_string(), expected);
     /// assert!(deck.same(&deck.shuffle()));
     /// ```
-    #[must_use]
     fn deck() -> Pile<Pinochle, French> {
         let ranks = Rank::<Pinochle>::ranks_from_array(&Pinochle::rank_names());
         let suits = Suit::<French>::suits();
@@ -234,8 +233,8 @@ impl Decked<Pinochle, French> for Pinochle {
 
         for suit in &suits {
             for rank in &ranks {
-                let card = Card::<Pinochle, French>::new(rank.clone(), suit.clone());
-                pile.push(card.clone());
+                let card = Card::<Pinochle, French>::new(*rank, *suit);
+                pile.push(card);
                 pile.push(card);
             }
         }
@@ -252,9 +251,20 @@ impl Decked<Pinochle, French> for Pinochle {
     }
 }
 
+impl Encoded<Pinochle, French> for Pinochle {}
+
+impl Literal for Pinochle {
+    type RankType = Pinochle;
+    type SuitType = French;
+
+    const RANK_CHARS: &'static [char] =
+        &['9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k', 'A', 'a'];
+    const SUIT_CHARS: &'static [char] = French::SUIT_CHARS;
+}
+
 impl Ranked for Pinochle {
     fn rank_chars() -> Vec<char> {
-        vec!['9', 'T', 't', '0', 'J', 'j', 'Q', 'q', 'K', 'k', 'A', 'a']
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
@@ -268,6 +278,18 @@ impl Ranked for Pinochle {
         ]
     }
 
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            'A' | 'a' => Some(Pinochle::ACE),
+            'T' | 't' | '0' => Some(Pinochle::TEN),
+            'K' | 'k' => Some(Pinochle::KING),
+            'Q' | 'q' => Some(Pinochle::QUEEN),
+            'J' | 'j' => Some(Pinochle::JACK),
+            '9' => Some(Pinochle::NINE),
+            _ => None,
+        }
+    }
+
     fn type_name() -> &'static str {
         Pinochle::DECK_NAME
     }
@@ -292,7 +314,7 @@ mod decks__pinochle__tests {
 
     #[test]
     fn rank__ranks_from_array() {
-        let ranks = Rank::<Pinochle>::ranks_from_array(&*Pinochle::rank_names());
+        let ranks = Rank::<Pinochle>::ranks_from_array(&Pinochle::rank_names());
 
         assert_eq!(ranks.len(), 6);
         assert_eq!(ranks[0].fluent_name_string(), "pinochle-ace");
@@ -332,4 +354,16 @@ mod decks__pinochle__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = Pinochle::distinct();
+
+        assert_eq!(distinct.len(), 24);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(Pinochle::ordinal(card), Some(i));
+            assert_eq!(Pinochle::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(Pinochle::from_ordinal(24), None);
+    }
 }
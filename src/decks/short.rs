@@ -2,7 +2,7 @@ use crate::decks::french::French;
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
-use crate::types::traits::{Decked, Ranked};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked};
 use std::str::FromStr;
 
 /// [Manila, aka Six Plus aka Short-deck](https://en.wikipedia.org/wiki/Six-plus_hold_%27em)
@@ -43,11 +43,21 @@ impl Decked<Short, French> for Short {
     }
 }
 
+impl Encoded<Short, French> for Short {}
+
+impl Literal for Short {
+    type RankType = Short;
+    type SuitType = French;
+
+    const RANK_CHARS: &'static [char] = &[
+        '6', '7', '8', '9', 'T', 't', 'J', 'j', 'Q', 'q', 'K', 'k', 'A', 'a',
+    ];
+    const SUIT_CHARS: &'static [char] = French::SUIT_CHARS;
+}
+
 impl Ranked for Short {
     fn rank_chars() -> Vec<char> {
-        vec![
-            '6', '7', '8', '9', 'T', 't', 'J', 'j', 'Q', 'q', 'K', 'k', 'A', 'a',
-        ]
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
@@ -64,6 +74,10 @@ impl Ranked for Short {
         ]
     }
 
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        French::rank_for_char(c)
+    }
+
     fn type_name() -> &'static str {
         Short::DECK_NAME
     }
@@ -109,4 +123,16 @@ mod decks__manila__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = Short::distinct();
+
+        assert_eq!(distinct.len(), 36);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(Short::ordinal(card), Some(i));
+            assert_eq!(Short::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(Short::from_ordinal(36), None);
+    }
 }
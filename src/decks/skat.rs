@@ -1,12 +1,14 @@
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
-use crate::types::traits::{Decked, Ranked, Suited};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked, Suited};
 use crate::types::utils::Bit;
 use colored::Color;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+/// Falls back to a blank card if the index isn't valid. `card!(Skat: "...")` checks the index
+/// when it's compiled instead.
 #[macro_export]
 #[allow(clippy::pedantic)]
 macro_rules! skat_card {
@@ -73,11 +75,23 @@ impl Decked<Skat, Skat> for Skat {
     }
 }
 
+impl Encoded<Skat, Skat> for Skat {}
+
+impl Literal for Skat {
+    type RankType = Skat;
+    type SuitType = Skat;
+
+    const RANK_CHARS: &'static [char] = &[
+        '7', '8', '9', 'T', 't', '0', 'U', 'u', 'O', 'o', 'K', 'k', 'D', 'd',
+    ];
+    const SUIT_CHARS: &'static [char] = &[
+        '♧', '♣', 'E', 'e', '♤', '♠', 'L', 'l', '♡', '♥', 'H', 'h', '♢', '♦', 'S', 's',
+    ];
+}
+
 impl Ranked for Skat {
     fn rank_chars() -> Vec<char> {
-        vec![
-            '7', '8', '9', 'T', 't', '0', 'U', 'u', 'O', 'o', 'K', 'k', 'D', 'd',
-        ]
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
@@ -93,6 +107,20 @@ impl Ranked for Skat {
         ]
     }
 
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            'D' | 'd' => Some(Skat::DAUS),
+            'T' | 't' | '0' => Some(Skat::ZHEN),
+            'K' | 'k' => Some(Skat::KÖNIG),
+            'O' | 'o' => Some(Skat::OBER),
+            'U' | 'u' => Some(Skat::UNTER),
+            '9' => Some(Skat::NEUN),
+            '8' => Some(Skat::ACHT),
+            '7' => Some(Skat::SIEBEN),
+            _ => None,
+        }
+    }
+
     fn type_name() -> &'static str {
         Skat::DECK_NAME
     }
@@ -110,15 +138,23 @@ impl Suited for Skat {
     }
 
     fn suit_chars() -> Vec<char> {
-        vec![
-            '♧', '♣', 'E', 'e', '♤', '♠', 'L', 'l', '♡', '♥', 'H', 'h', '♢', '♦', 'S', 's',
-        ]
+        Self::SUIT_CHARS.to_vec()
     }
 
     fn suit_names() -> Vec<&'static str> {
         vec![Skat::EICHEL, Skat::LAUB, Skat::HERZ, Skat::SHELLEN]
     }
 
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            '♧' | '♣' | 'E' | 'e' => Some(Skat::EICHEL),
+            '♤' | '♠' | 'L' | 'l' => Some(Skat::LAUB),
+            '♡' | '♥' | 'H' | 'h' => Some(Skat::HERZ),
+            '♢' | '♦' | 'S' | 's' => Some(Skat::SHELLEN),
+            _ => None,
+        }
+    }
+
     fn type_name() -> &'static str {
         Skat::DECK_NAME
     }
@@ -148,7 +184,7 @@ mod decks__skat__tests {
         assert_eq!(expected, skat_blank);
         assert_eq!(expected, macro_blank);
         assert_eq!(expected, card_default);
-        assert!(skat_blank.is_blank())
+        assert!(skat_blank.is_blank());
     }
 
     #[test]
@@ -245,4 +281,16 @@ mod decks__skat__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = Skat::distinct();
+
+        assert_eq!(distinct.len(), 32);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(Skat::ordinal(card), Some(i));
+            assert_eq!(Skat::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(Skat::from_ordinal(32), None);
+    }
 }
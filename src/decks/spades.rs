@@ -3,10 +3,11 @@ use crate::decks::modern::Modern;
 use crate::modern_card;
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
+use crate::types::deck_builder::DeckBuilder;
 use crate::types::pile::Pile;
 use crate::types::rank::Rank;
 use crate::types::suit::Suit;
-use crate::types::traits::Decked;
+use crate::types::traits::{Decked, Encoded, Literal};
 use std::str::FromStr;
 
 #[macro_export]
@@ -34,7 +35,7 @@ impl Spades {
     fn card(s: &str) -> Card<Modern, Modern> {
         let card = modern_card!(s);
 
-        match card.index.as_str() {
+        match card.index {
             "2C" | "2D" => Card::<Modern, Modern>::default(),
             _ => card,
         }
@@ -42,33 +43,18 @@ impl Spades {
 
     /// # Errors
     ///
-    /// Returns a `CardError` if the index is out of bounds.
-    ///
-    /// TODO: Add deck validation
+    /// Returns a `CardError` if the index is out of bounds, or if the cards aren't valid
+    /// for a `Spades` deck, such as the 2♣ or 2♦.
     #[allow(dead_code, clippy::should_implement_trait)]
     fn from_str(index: &str) -> Result<Pile<Modern, Modern>, CardError> {
         let pile = Pile::<Modern, Modern>::from_str(index)?;
-
-        if pile.contains(&modern_card!("2C")) {
-            return Err(CardError::InvalidCard(
-                "2♣ is not in a Spades deck".to_string(),
-            ));
-        }
-
-        if pile.contains(&modern_card!("2D")) {
-            return Err(CardError::InvalidCard(
-                "2♦ is not in a Spades deck".to_string(),
-            ));
-        }
-
+        Spades::validate(&pile)?;
         Ok(pile)
     }
 }
 
 impl Decked<Modern, Modern> for Spades {
     fn deck() -> Pile<Modern, Modern> {
-        let mut deck = Modern::deck();
-
         let two_clubs = Card::new(
             Rank::<Modern>::new(French::TWO),
             Suit::<Modern>::new(French::CLUBS),
@@ -78,10 +64,12 @@ impl Decked<Modern, Modern> for Spades {
             Suit::<Modern>::new(French::DIAMONDS),
         );
 
-        deck.remove_card(&two_clubs).unwrap();
-        deck.remove_card(&two_diamonds).unwrap();
-
-        deck
+        DeckBuilder::from_decked::<Modern>()
+            .remove(two_clubs)
+            .remove(two_diamonds)
+            .build()
+            .map(Pile::from)
+            .expect("the Spades deck is built from a Modern deck")
     }
 
     fn blank() -> Card<Modern, Modern> {
@@ -93,6 +81,18 @@ impl Decked<Modern, Modern> for Spades {
     }
 }
 
+impl Encoded<Modern, Modern> for Spades {}
+
+impl Literal for Spades {
+    type RankType = Modern;
+    type SuitType = Modern;
+
+    const RANK_CHARS: &'static [char] = Modern::RANK_CHARS;
+    const SUIT_CHARS: &'static [char] = Modern::SUIT_CHARS;
+    const SPECIAL_RANK_CHARS: &'static [char] = Modern::SPECIAL_RANK_CHARS;
+    const SPECIAL_SUIT_CHARS: &'static [char] = Modern::SPECIAL_SUIT_CHARS;
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod decks__spades__tests {
@@ -118,6 +118,18 @@ mod decks__spades__tests {
         assert!(Spades::from_str("2C").is_err());
         assert!(Spades::from_str("2D").is_err());
         assert!(Spades::from_str("2H 2C").is_err());
+        assert_eq!(
+            Spades::from_str("2H 2C 2D").unwrap_err(),
+            CardError::NotInDeck("2C 2D".to_string())
+        );
+        assert_eq!(
+            Spades::from_str("2H 2H").unwrap_err(),
+            CardError::TooManyCopies("2H".to_string())
+        );
+        assert_eq!(
+            Spades::from_str("2H 2C 2H").unwrap_err(),
+            CardError::NotInDeckAndTooManyCopies("2C".to_string(), "2H".to_string())
+        );
     }
 
     #[test]
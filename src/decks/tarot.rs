@@ -1,31 +1,13 @@
 use crate::decks::french::French;
+use crate::localization::{FluentName, Named};
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
 use crate::types::pile::Pile;
-use crate::types::rank::Rank;
-use crate::types::suit::Suit;
-use crate::types::traits::{Decked, Ranked, Suited};
+use crate::types::traits::{Decked, Encoded, Literal, Ranked, Suited};
 use colored::Color;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-// #[macro_export] These macros doesn't work yet.
-#[allow(unused_macros, clippy::pedantic)]
-macro_rules! tarot_card {
-    ($card_str:expr) => {
-        Card::<Tarot, Tarot>::from_str($card_str)
-            .unwrap_or_else(|_| Card::<Tarot, Tarot>::default())
-    };
-}
-
-// #[macro_export]
-#[allow(unused_macros, clippy::pedantic)]
-macro_rules! tarot {
-    ($card_str:expr) => {
-        Pile::<Tarot, Tarot>::from_str($card_str)
-    };
-}
-
 /// The great thing about trying to get T
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Tarot {}
@@ -99,65 +81,6 @@ impl Tarot {
     pub const KNIGHT_SYMBOL: char = '🗡';
     pub const PAGE_SYMBOL: char = '📜';
 
-    fn major_arcana() -> Pile<Tarot, Tarot> {
-        let mut pile = Pile::<Tarot, Tarot>::from(Vec::new());
-
-        for rank in Tarot::major_arcana_rank_names() {
-            pile.push(Tarot::major_arcana_card_factory(rank));
-        }
-
-        pile
-    }
-
-    fn major_arcana_card_factory(raw: &str) -> Card<Tarot, Tarot> {
-        Card::<Tarot, Tarot>::new(
-            Rank::<Tarot>::new(raw),
-            Suit::<Tarot>::new(Tarot::MAJOR_ARCANA),
-        )
-    }
-
-    fn major_arcana_rank_names() -> Vec<&'static str> {
-        vec![
-            Tarot::FOOL,
-            Tarot::MAGICIAN,
-            Tarot::PRIESTESS,
-            Tarot::EMPRESS,
-            Tarot::EMPEROR,
-            Tarot::HIEROPHANT,
-            Tarot::LOVERS,
-            Tarot::CHARIOT,
-            Tarot::STRENGTH,
-            Tarot::HERMIT,
-            Tarot::FORTUNE,
-            Tarot::JUSTICE,
-            Tarot::HANGED,
-            Tarot::DEATH,
-            Tarot::TEMPERANCE,
-            Tarot::DEVIL,
-            Tarot::TOWER,
-            Tarot::STAR,
-            Tarot::MOON,
-            Tarot::SUN,
-            Tarot::JUDGEMENT,
-            Tarot::WORLD,
-        ]
-    }
-
-    fn minor_arcana() -> Pile<Tarot, Tarot> {
-        let ranks = Rank::<Tarot>::ranks_from_array(&Tarot::rank_names());
-        let suits = Suit::<Tarot>::suits();
-
-        let mut pile = Pile::<Tarot, Tarot>::from(Vec::new());
-
-        for suit in &suits {
-            for rank in &ranks {
-                pile.push(Card::<Tarot, Tarot>::new(rank.clone(), suit.clone()));
-            }
-        }
-
-        pile
-    }
-
     /// # Errors
     ///
     /// Returns a `CardError` if the index is out of bounds.
@@ -168,15 +91,6 @@ impl Tarot {
 }
 
 impl Decked<Tarot, Tarot> for Tarot {
-    fn deck() -> Pile<Tarot, Tarot> {
-        let mut major_arcana = Tarot::major_arcana();
-        let minor_arcana = Tarot::minor_arcana();
-
-        major_arcana.extend(&minor_arcana);
-
-        major_arcana
-    }
-
     fn blank() -> Card<Tarot, Tarot> {
         Card::<Tarot, Tarot>::default()
     }
@@ -186,13 +100,44 @@ impl Decked<Tarot, Tarot> for Tarot {
     }
 }
 
+impl Encoded<Tarot, Tarot> for Tarot {}
+
+impl Literal for Tarot {
+    type RankType = Tarot;
+    type SuitType = Tarot;
+
+    const RANK_CHARS: &'static [char] = &[
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 't', '0', 'P', 'p', 'J', 'j', 'Q', 'q', 'K',
+        'k', 'A', 'a', '🤡', '🧙', '😇', '👑', '🤴', '🧎', '💏', '🏎', '💪', '💡', '🍀', '⚖', '🙃',
+        '💀', '🚭', '😈', '🏢', '⭐', '🌙', '🌞', '🔔', '🌍', '🗡', '📜',
+    ];
+    const SUIT_CHARS: &'static [char] = &[
+        Tarot::MAJOR_ARCANA_SYMBOL,
+        'M',
+        'm',
+        Tarot::WANDS_SYMBOL,
+        'W',
+        'w',
+        Tarot::CUPS_SYMBOL,
+        'C',
+        'c',
+        Tarot::SWORDS_SYMBOL,
+        'S',
+        's',
+        Tarot::PENTACLES_SYMBOL,
+        'P',
+        'p',
+    ];
+    const SPECIAL_RANK_CHARS: &'static [char] = &[
+        '🤡', '🧙', '😇', '👑', '🤴', '🧎', '💏', '🏎', '💪', '💡', '🍀', '⚖', '🙃', '💀', '🚭',
+        '😈', '🏢', '⭐', '🌙', '🌞', '🔔', '🌍',
+    ];
+    const SPECIAL_SUIT_CHARS: &'static [char] = &[Tarot::MAJOR_ARCANA_SYMBOL, 'M', 'm'];
+}
+
 impl Ranked for Tarot {
     fn rank_chars() -> Vec<char> {
-        vec![
-            '2', '3', '4', '5', '6', '7', '8', '9', 'T', 't', '0', 'P', 'p', 'J', 'j', 'Q', 'q',
-            'K', 'k', 'A', 'a', '🤡', '🧙', '😇', '👑', '🤴', '🧎', '💏', '🏎', '💪', '💡', '🍀',
-            '⚖', '🙃', '💀', '🚭', '😈', '🏢', '⭐', '🌙', '🌞', '🔔', '🌍', '🗡', '📜',
-        ]
+        Self::RANK_CHARS.to_vec()
     }
 
     fn rank_names() -> Vec<&'static str> {
@@ -214,6 +159,74 @@ impl Ranked for Tarot {
         ]
     }
 
+    fn special_rank_names() -> Vec<&'static str> {
+        vec![
+            Tarot::FOOL,
+            Tarot::MAGICIAN,
+            Tarot::PRIESTESS,
+            Tarot::EMPRESS,
+            Tarot::EMPEROR,
+            Tarot::HIEROPHANT,
+            Tarot::LOVERS,
+            Tarot::CHARIOT,
+            Tarot::STRENGTH,
+            Tarot::HERMIT,
+            Tarot::FORTUNE,
+            Tarot::JUSTICE,
+            Tarot::HANGED,
+            Tarot::DEATH,
+            Tarot::TEMPERANCE,
+            Tarot::DEVIL,
+            Tarot::TOWER,
+            Tarot::STAR,
+            Tarot::MOON,
+            Tarot::SUN,
+            Tarot::JUDGEMENT,
+            Tarot::WORLD,
+        ]
+    }
+
+    /// The minor arcana ranks are weighted by their place in the suit, with the aces at the
+    /// bottom, while the major arcana keep their weights from the fluent templates.
+    #[allow(clippy::cast_possible_truncation)]
+    fn rank_weight(name: &str) -> u32 {
+        let ranks = Tarot::rank_names();
+        match ranks.iter().position(|rank| *rank == name) {
+            Some(i) => (ranks.len() - 1 - i) as u32,
+            None => FluentName::new(name).weight(),
+        }
+    }
+
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        match c {
+            'P' | 'p' | '📜' => Some(Tarot::PAGE),
+            'J' | 'j' | '🗡' => Some(Tarot::KNIGHT),
+            '🤡' => Some(Tarot::FOOL),
+            '🧙' => Some(Tarot::MAGICIAN),
+            '😇' => Some(Tarot::PRIESTESS),
+            '👑' => Some(Tarot::EMPRESS),
+            '🤴' => Some(Tarot::EMPEROR),
+            '🧎' => Some(Tarot::HIEROPHANT),
+            '💏' => Some(Tarot::LOVERS),
+            '🏎' => Some(Tarot::CHARIOT),
+            '💪' => Some(Tarot::STRENGTH),
+            '💡' => Some(Tarot::HERMIT),
+            '🍀' => Some(Tarot::FORTUNE),
+            '⚖' => Some(Tarot::JUSTICE),
+            '🙃' => Some(Tarot::HANGED),
+            '💀' => Some(Tarot::DEATH),
+            '🚭' => Some(Tarot::TEMPERANCE),
+            '😈' => Some(Tarot::DEVIL),
+            '🏢' => Some(Tarot::TOWER),
+            '⭐' => Some(Tarot::STAR),
+            '🌙' => Some(Tarot::MOON),
+            '🌞' => Some(Tarot::SUN),
+            '🔔' => Some(Tarot::JUDGEMENT),
+            '🌍' => Some(Tarot::WORLD),
+            _ => French::rank_for_char(c),
+        }
+    }
+
     fn type_name() -> &'static str {
         Tarot::DECK_NAME
     }
@@ -231,29 +244,28 @@ impl Suited for Tarot {
     }
 
     fn suit_chars() -> Vec<char> {
-        vec![
-            Tarot::MAJOR_ARCANA_SYMBOL,
-            'M',
-            'm',
-            Tarot::WANDS_SYMBOL,
-            'W',
-            'w',
-            Tarot::CUPS_SYMBOL,
-            'C',
-            'c',
-            Tarot::SWORDS_SYMBOL,
-            'S',
-            's',
-            Tarot::PENTACLES_SYMBOL,
-            'P',
-            'p',
-        ]
+        Self::SUIT_CHARS.to_vec()
     }
 
     fn suit_names() -> Vec<&'static str> {
         vec![Tarot::WANDS, Tarot::CUPS, Tarot::SWORDS, Tarot::PENTACLES]
     }
 
+    fn special_suit_names() -> Vec<&'static str> {
+        vec![Tarot::MAJOR_ARCANA]
+    }
+
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        match *c {
+            Tarot::MAJOR_ARCANA_SYMBOL | 'm' => Some(Tarot::MAJOR_ARCANA),
+            Tarot::WANDS_SYMBOL | 'W' | 'w' => Some(Tarot::WANDS),
+            Tarot::CUPS_SYMBOL | 'C' | 'c' => Some(Tarot::CUPS),
+            Tarot::SWORDS_SYMBOL | 'S' | 's' => Some(Tarot::SWORDS),
+            Tarot::PENTACLES_SYMBOL | 'P' | 'p' => Some(Tarot::PENTACLES),
+            _ => None,
+        }
+    }
+
     fn type_name() -> &'static str {
         Tarot::DECK_NAME
     }
@@ -263,13 +275,22 @@ impl Suited for Tarot {
 #[allow(non_snake_case)]
 mod decks__tarot__tests {
     use super::*;
-    // use rstest::rstest;
+    use crate::types::suit::Suit;
+    use crate::{card, pile};
 
     #[test]
-    fn macro__tarot_card() {
-        let card = tarot_card!("🤡M");
+    fn macro__card() {
+        let card = card!(Tarot: "🤡M");
 
         assert_eq!(card.to_string(), "🤡M");
+        assert_eq!(card.rank.name.fluent_name_string(), Tarot::FOOL);
+    }
+
+    #[test]
+    fn macro__pile() {
+        let pile = pile!(Tarot: "🧙M 😇m A🪄 KW");
+
+        assert_eq!(pile.to_string(), "🧙M 😇M A🪄 K🪄");
     }
 
     #[test]
@@ -286,6 +307,27 @@ mod decks__tarot__tests {
         assert_eq!(deck.to_string(), "🤡M 🧙M 😇M 👑M 🤴M 🧎M 💏M 🏎\u{fe0f}M 💪M 🧑\u{200d}🌾M 🍀M ⚖M 🙃M 💀M 🚭M 😈M 🏢M ⭐M 🌙M 🌞M 🔔M 🌍M K🪄 Q🪄 J🪄 P🪄 T🪄 9🪄 8🪄 7🪄 6🪄 5🪄 4🪄 3🪄 2🪄 A🪄 K🏆 Q🏆 J🏆 P🏆 T🏆 9🏆 8🏆 7🏆 6🏆 5🏆 4🏆 3🏆 2🏆 A🏆 K⚔ Q⚔ J⚔ P⚔ T⚔ 9⚔ 8⚔ 7⚔ 6⚔ 5⚔ 4⚔ 3⚔ 2⚔ A⚔ K☆ Q☆ J☆ P☆ T☆ 9☆ 8☆ 7☆ 6☆ 5☆ 4☆ 3☆ 2☆ A☆");
     }
 
+    #[test]
+    fn deck__two_tiers() {
+        let mut deck = Tarot::deck();
+        let by_suit = deck.map_by_suit();
+        let major_arcana = &by_suit[&Suit::<Tarot>::new(Tarot::MAJOR_ARCANA)];
+
+        assert_eq!(by_suit.len(), 5);
+        assert_eq!(major_arcana.len(), 22);
+        assert_eq!(major_arcana.to_string(), deck.draw(22).to_string());
+        assert!(major_arcana.v().iter().all(|card| card.suit.is_special()));
+    }
+
+    #[test]
+    fn from_str__minor_arcana_weights() {
+        let parsed = Tarot::from_str("AW KW 🤡M").unwrap();
+
+        assert!(Tarot::deck().contains(&parsed[0]));
+        assert_eq!(parsed[0].rank.weight, 0);
+        assert_eq!(parsed.sort().to_string(), "🤡M K🪄 A🪄");
+    }
+
     #[test]
     fn pile__sort() {
         let deck = Tarot::deck();
@@ -297,33 +339,27 @@ mod decks__tarot__tests {
         assert_eq!(deck.to_string(), shuffled.to_string());
     }
 
-    // #[rstest]
-    // #[ignore]
-    // #[case("🤡M")]
-    // #[case("🧙M")]
-    // #[case("😇M")]
-    // #[case("👑M")]
-    // #[case("🤴M")]
-    // #[case("🧎M")]
-    // #[case("💏M")]
-    // // #[case("🚗M")]
-    // fn card(#[case] s: &str) {
-    //     let s = s.trim();
-    //     if let Some(c) = s.chars().next() {
-    //         let rank = Rank::<Tarot>::from(c);
-    //         println!("{:?}", rank);
-    //         if let Some(d) = s.chars().last() {
-    //             let suit = Suit::<Tarot>::from(d);
-    //             println!("{:?}", suit);
-    //         }
-    //     }
-    //     let card = tarot_card!(s);
-    //     println!("{:?}", card);
-    //     println!();
-    // }
+    #[test]
+    fn sort__shuffled() {
+        let deck = Tarot::deck();
+
+        assert_ne!(deck.shuffle_seeded(3), deck);
+        assert_eq!(deck.shuffle_seeded(3).sort(), deck);
+    }
+
+    #[test]
+    fn map_by_suit__major_arcana() {
+        let map = Tarot::deck().shuffle_seeded(3).map_by_suit();
+
+        assert_eq!(map.len(), 5);
+        assert_eq!(
+            map[&Suit::<Tarot>::new(Tarot::MAJOR_ARCANA)].sort(),
+            Tarot::deck().draw(22)
+        );
+        assert_eq!(map[&Suit::<Tarot>::new(Tarot::WANDS)].len(), 14);
+    }
 
     #[test]
-    #[ignore]
     fn to_string__from_str() {
         let deck = Tarot::deck();
         let shuffled = deck.shuffle().to_string();
@@ -331,4 +367,16 @@ mod decks__tarot__tests {
 
         assert!(deck.same(&parsed));
     }
+
+    #[test]
+    fn decked__ordinal() {
+        let distinct = Tarot::distinct();
+
+        assert_eq!(distinct.len(), 78);
+        for (i, card) in distinct.v().iter().enumerate() {
+            assert_eq!(Tarot::ordinal(card), Some(i));
+            assert_eq!(Tarot::from_ordinal(i).as_ref(), Some(card));
+        }
+        assert_eq!(Tarot::from_ordinal(78), None);
+    }
 }
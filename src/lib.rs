@@ -52,8 +52,17 @@
 //! ```
 //!
 //! An important thing to remember about the decks is that the cards have their weight inside them
-//! to facilitate sorting. If you wanted a deck for Razz poker, where the lowest hand wins, you
-//! would need to create a separate deck file with the card's `Rank` weights inverted.
+//! to facilitate sorting. If you wanted to sort a deck for Razz poker, where the ace is low, you
+//! can sort it with a [`RankProfile`](types::rank_profile::RankProfile) instead of creating a
+//! separate deck file:
+//!
+//! ```
+//! use cardpack::prelude::*;
+//!
+//! let hand = French::from_str("AS 2S KS").unwrap();
+//!
+//! assert_eq!(hand.sort_by_ordering(&RankProfile::AceLow).to_string(), "K♠ 2♠ A♠");
+//! ```
 //!
 //! ## Modern Deck
 //!
@@ -114,6 +123,15 @@
 //!         ]
 //!     }
 //!
+//!     // Maps the index chars to the rank names. Decks are free to use whatever chars they want.
+//!     fn rank_for_char(c: &char) -> Option<&'static str> {
+//!         match c {
+//!             'A' | 'a' => Some(French::ACE),
+//!             'K' | 'k' => Some(French::KING),
+//!             _ => None,
+//!         }
+//!     }
+//!
 //!     fn type_name() -> &'static str {
 //!         Tiny::DECK_NAME
 //!     }
@@ -138,6 +156,14 @@
 //!         ]
 //!     }
 //!
+//!     fn suit_for_char(c: &char) -> Option<&'static str> {
+//!         match c {
+//!             '♤' | '♠' | 'S' | 's' => Some(French::SPADES),
+//!             '♡' | '♥' | 'H' | 'h' => Some(French::HEARTS),
+//!             _ => None,
+//!         }
+//!     }
+//!
 //!     fn type_name() -> &'static str {
 //!         Tiny::DECK_NAME
 //!     }
@@ -184,13 +210,31 @@
 //!
 //! // Draw the top card and make sure it's got the right Cactus Kev Card Number for the
 //! // King of Spades:
-//! assert_eq!(deck.draw_first().unwrap().get_ckc_number(), 0b00001000_00000000_10001011_00100101);
+//! assert_eq!(deck.draw_first().unwrap().get_ckc_number(), Some(0b00001000_00000000_10001011_00100101));
 //!
 //! // Draw the last card:
 //! assert_eq!(deck.draw_first().unwrap(), tiny!(AH));
 //!
 //! // And now the deck is empty:
 //! assert!(deck.draw_first().is_none());
+//!
+//! // Tiny cards can be parsed from their indexes:
+//! let hand = Pile::<Tiny, Tiny>::from_str("A♤ kh").unwrap();
+//! assert_eq!(hand.index(), "AS KH");
+//! ```
+//!
+//! With the `derive` feature, all of that, including the `tiny!` macro, can be derived from a
+//! couple of attributes instead. See [cardpack-derive](https://docs.rs/cardpack-derive) for
+//! the details.
+//!
+//! ```ignore
+//! use cardpack::prelude::*;
+//!
+//! #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+//! #[derive(Ranked, Suited, Decked)]
+//! #[ranks(ace = 'A', king = 'K')]
+//! #[suits(spades('♠', 'S'), hearts('♥', 'H', color = Red))]
+//! pub struct Tiny {}
 //! ```
 
 #![allow(clippy::needless_doctest_main)] // want this just for the README.md
@@ -200,3 +244,30 @@ pub mod decks;
 pub mod localization;
 pub mod prelude;
 pub mod types;
+
+#[cfg(feature = "derive")]
+pub use cardpack_derive::{Decked, Ranked, Suited};
+
+/// Used by the code that's generated by the derive macros. Not part of the public API.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use colored::Color;
+
+    /// Returns the name of one of the deck's ranks or suits, panicking if it isn't in the fluent
+    /// templates and hasn't been declared, since its cards would otherwise quietly be blank.
+    ///
+    /// # Panics
+    ///
+    /// If the name isn't a known [`FluentName`](crate::localization::FluentName).
+    #[must_use]
+    pub fn known_name(deck: &str, name: &'static str) -> &'static str {
+        if let Err(e) = name.parse::<crate::localization::FluentName>() {
+            panic!(
+                "{e} Add it to the fluent templates with `LayeredLoader`, or declare it with \
+                 `FluentName::declare()`, before using the `{deck}` deck."
+            );
+        }
+        name
+    }
+}
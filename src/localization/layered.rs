@@ -0,0 +1,408 @@
+//! Fluent resources added while the program is running, layered over the ones built into the
+//! crate, so that an app can add a language, or its own names for cards, without forking it.
+use crate::localization::{FluentName, Interned, Named, LOCALES};
+use crate::types::card_error::CardError;
+use fluent_syntax::ast::Entry;
+use fluent_templates::fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use fluent_templates::{FluentBundle, LanguageIdentifier, Loader};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+type Bundle = FluentBundle<Arc<FluentResource>>;
+
+/// A fluent loader, in the style of `fluent_templates::ArcLoader`, that looks up messages in
+/// the resources added to it before the built in [`LOCALES`]. Every fluent lookup in the
+/// library, including [`Named::fluent_value()`], goes through [`LayeredLoader::global()`].
+///
+/// For each language, from the one asked for, to just its language code, to `US_ENGLISH`, the
+/// added resources are tried before the built in ones. A German app can override a single name
+/// in `de` and still get the rest of the German names, while a new language only needs the
+/// names that differ from English.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::localization::LayeredLoader;
+/// use fluent_templates::langid;
+///
+/// let french = langid!("fr");
+/// LayeredLoader::global()
+///     .add_resource(&french, "ace-long = As\nhearts-long = Cœur")
+///     .unwrap();
+///
+/// assert_eq!(card!(AH).long(&french), "As Cœur");
+/// assert_eq!(card!(KH).long(&french), "King Cœur");
+/// ```
+///
+/// A card's `US_ENGLISH` index, weight and prime are fixed when it's made, so once a name has
+/// them they can't be changed. Adding a resource that would is an error:
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::localization::LayeredLoader;
+///
+/// let loader = LayeredLoader::default();
+///
+/// assert!(loader.add_resource(&FluentName::US_ENGLISH, "king-index = R").is_err());
+/// assert!(loader.add_resource(&FluentName::US_ENGLISH, "king-long = Rex").is_ok());
+/// ```
+#[derive(Default)]
+pub struct LayeredLoader {
+    bundles: RwLock<HashMap<LanguageIdentifier, Bundle>>,
+}
+
+impl LayeredLoader {
+    /// The loader that the library uses.
+    pub fn global() -> &'static LayeredLoader {
+        static GLOBAL: OnceLock<LayeredLoader> = OnceLock::new();
+        GLOBAL.get_or_init(LayeredLoader::default)
+    }
+
+    /// The sections of a name that its cards are built with.
+    const FIXED_SECTIONS: [&'static str; 3] = [
+        FluentName::FLUENT_INDEX_SECTION,
+        FluentName::FLUENT_WEIGHT_SECTION,
+        FluentName::FLUENT_PRIME_SECTION,
+    ];
+
+    /// Adds the FTL source to the language, overriding any messages that are already there.
+    /// Any `US_ENGLISH` values that have already been looked up are looked up again.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::localization::LayeredLoader;
+    ///
+    /// let loader = LayeredLoader::global();
+    /// loader.add_resource(&FluentName::US_ENGLISH, "wild-index = W").unwrap();
+    /// let wild = FluentName::new("wild");
+    /// assert_ne!(wild.long_default(), "Wild");
+    ///
+    /// loader.add_resource(&FluentName::US_ENGLISH, "wild-long = Wild").unwrap();
+    ///
+    /// assert_eq!(wild.long_default(), "Wild");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentResource` if the source isn't valid FTL, or if it
+    /// changes the `US_ENGLISH` index, weight or prime of a name, in which case none of it is
+    /// added.
+    pub fn add_resource(&self, lid: &LanguageIdentifier, source: &str) -> Result<(), CardError> {
+        let resource = FluentResource::try_new(source.to_string()).map_err(|(_, errors)| {
+            let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            CardError::InvalidFluentResource(errors.join(", "))
+        })?;
+        let resource = Arc::new(resource);
+        if *lid == FluentName::US_ENGLISH {
+            self.check_fixed(&resource)?;
+        }
+
+        let ids: Vec<String> = {
+            let mut bundles = self.write();
+            let bundle = bundles
+                .entry(lid.clone())
+                .or_insert_with(|| Bundle::new_concurrent(vec![lid.clone()]));
+            bundle.add_resource_overriding(resource);
+            LayeredLoader::cached_ids(lid, bundle)
+        };
+        Interned::forget(&ids);
+        Ok(())
+    }
+
+    /// Adds the FTL file to the language.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentResource` if the file can't be read or isn't valid FTL.
+    pub fn add_file(
+        &self,
+        lid: &LanguageIdentifier,
+        path: impl AsRef<Path>,
+    ) -> Result<(), CardError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| CardError::InvalidFluentResource(format!("{}: {e}", path.display())))?;
+        self.add_resource(lid, &source)
+    }
+
+    /// Adds every `.ftl` file in a directory laid out the same way as the built in locales, and
+    /// the ones read by `ArcLoader`, with a directory for each language, such as `fr/cards.ftl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentResource` if a directory or file can't be read, or a file
+    /// isn't valid FTL. The files before it will have already been added.
+    pub fn add_dir(&self, path: impl AsRef<Path>) -> Result<(), CardError> {
+        for (lid, file) in LayeredLoader::ftl_files(path.as_ref())? {
+            self.add_file(&lid, file)?;
+        }
+        Ok(())
+    }
+
+    /// Removes everything that's been added, leaving just the built in resources.
+    pub fn clear(&self) {
+        let ids: Vec<String> = {
+            let mut bundles = self.write();
+            let ids = bundles
+                .get(&FluentName::US_ENGLISH)
+                .map(|bundle| LayeredLoader::cached_ids(&FluentName::US_ENGLISH, bundle))
+                .unwrap_or_default();
+            bundles.clear();
+            ids
+        };
+        Interned::forget(&ids);
+    }
+
+    /// Makes sure that the resource doesn't give a name that already has an index, weight or
+    /// prime a different one.
+    fn check_fixed(&self, resource: &Arc<FluentResource>) -> Result<(), CardError> {
+        let mut bundle = Bundle::new_concurrent(vec![FluentName::US_ENGLISH]);
+        bundle.add_resource_overriding(Arc::clone(resource));
+
+        let changed: Vec<String> = resource
+            .entries()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some(message.id.name),
+                _ => None,
+            })
+            .filter_map(|id| {
+                let (name, section) = id.rsplit_once('-')?;
+                LayeredLoader::FIXED_SECTIONS
+                    .contains(&section)
+                    .then_some(())?;
+                let existing = self.try_lookup(&FluentName::US_ENGLISH, id).or_else(|| {
+                    let name = FluentName::known(name).ok()?;
+                    Some(
+                        name.defined_value(section, &FluentName::US_ENGLISH)?
+                            .to_string(),
+                    )
+                })?;
+                let value = LayeredLoader::format(&bundle, id, None)?;
+                (value != existing).then(|| format!("`{id}` is already `{existing}`"))
+            })
+            .collect();
+
+        if changed.is_empty() {
+            Ok(())
+        } else {
+            Err(CardError::InvalidFluentResource(changed.join(", ")))
+        }
+    }
+
+    /// The languages to look in, from the one asked for to `US_ENGLISH`.
+    fn chain(lid: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+        let mut chain = vec![lid.clone()];
+        let language = LanguageIdentifier::from_parts(lid.language, None, None, &[]);
+        if !chain.contains(&language) {
+            chain.push(language);
+        }
+        if !chain.contains(&FluentName::US_ENGLISH) {
+            chain.push(FluentName::US_ENGLISH);
+        }
+        chain
+    }
+
+    /// The ids of the cached `US_ENGLISH` values that the bundle now has its own message for.
+    fn cached_ids(lid: &LanguageIdentifier, bundle: &Bundle) -> Vec<String> {
+        if *lid == FluentName::US_ENGLISH {
+            Interned::cached_ids()
+                .into_iter()
+                .filter(|id| bundle.has_message(id))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    fn format(
+        bundle: &Bundle,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Option<String> {
+        let pattern = bundle.get_message(text_id)?.value()?;
+        let args: Option<FluentArgs> = args.map(|args| {
+            args.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        });
+        let mut errors = vec![];
+        let value = bundle.format_pattern(pattern, args.as_ref(), &mut errors);
+        errors.is_empty().then(|| value.into_owned())
+    }
+
+    fn ftl_files(path: &Path) -> Result<Vec<(LanguageIdentifier, std::path::PathBuf)>, CardError> {
+        let read_dir = |path: &Path| {
+            let mut paths = std::fs::read_dir(path)
+                .and_then(|entries| {
+                    entries
+                        .map(|entry| entry.map(|entry| entry.path()))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .map_err(|e| {
+                    CardError::InvalidFluentResource(format!("{}: {e}", path.display()))
+                })?;
+            paths.sort();
+            Ok::<_, CardError>(paths)
+        };
+
+        let mut files = vec![];
+        for dir in read_dir(path)?.into_iter().filter(|p| p.is_dir()) {
+            let Some(lid) = dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse::<LanguageIdentifier>().ok())
+            else {
+                continue;
+            };
+            for file in read_dir(&dir)? {
+                if file.extension().is_some_and(|ext| ext == "ftl") {
+                    files.push((lid.clone(), file));
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<LanguageIdentifier, Bundle>> {
+        self.bundles.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<LanguageIdentifier, Bundle>> {
+        self.bundles.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Loader for LayeredLoader {
+    fn lookup_complete(
+        &self,
+        lang: &LanguageIdentifier,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> String {
+        self.try_lookup_complete(lang, text_id, args)
+            .unwrap_or_else(|| LOCALES.lookup_complete(lang, text_id, args))
+    }
+
+    fn try_lookup_complete(
+        &self,
+        lang: &LanguageIdentifier,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Option<String> {
+        let bundles = self.read();
+        LayeredLoader::chain(lang).iter().find_map(|lid| {
+            bundles
+                .get(lid)
+                .and_then(|bundle| LayeredLoader::format(bundle, text_id, args))
+                .or_else(|| LOCALES.lookup_single_language(lid, text_id, args).ok())
+        })
+    }
+
+    /// The built in languages. The ones that have been added can't be borrowed past the lock
+    /// that guards them.
+    fn locales(&self) -> Box<dyn Iterator<Item = &LanguageIdentifier> + '_> {
+        LOCALES.locales()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod localization__layered__tests {
+    use super::*;
+    use fluent_templates::langid;
+
+    #[test]
+    fn add_resource() {
+        let swiss = langid!("de-CH");
+        let loader = LayeredLoader::default();
+        loader.add_resource(&swiss, "ten-long = Zäni").unwrap();
+
+        assert_eq!(loader.lookup(&swiss, "ten-long"), "Zäni");
+        assert_eq!(loader.lookup(&swiss, "queen-long"), "Dame");
+        assert_eq!(loader.lookup(&FluentName::DEUTSCH, "ten-long"), "Zehn");
+    }
+
+    #[test]
+    fn add_resource__overrides() {
+        let loader = LayeredLoader::default();
+        loader
+            .add_resource(&FluentName::US_ENGLISH, "layered-test-long = Wild")
+            .unwrap();
+        assert_eq!(
+            loader.lookup(&FluentName::US_ENGLISH, "layered-test-long"),
+            "Wild"
+        );
+
+        loader
+            .add_resource(&FluentName::US_ENGLISH, "layered-test-long = Tame")
+            .unwrap();
+
+        assert_eq!(
+            loader.lookup(&FluentName::US_ENGLISH, "layered-test-long"),
+            "Tame"
+        );
+    }
+
+    #[test]
+    fn add_resource__fixed_sections() {
+        let loader = LayeredLoader::default();
+
+        assert_eq!(
+            loader.add_resource(&FluentName::US_ENGLISH, "ace-long = One\nking-index = R"),
+            Err(CardError::InvalidFluentResource(
+                "`king-index` is already `K`".to_string()
+            ))
+        );
+        assert_eq!(loader.lookup(&FluentName::US_ENGLISH, "ace-long"), "Ace");
+        assert!(loader
+            .add_resource(&FluentName::US_ENGLISH, "king-weight = 0")
+            .is_err());
+        assert!(loader
+            .add_resource(&FluentName::US_ENGLISH, "king-index = K\nwild-index = W")
+            .is_ok());
+        assert!(loader
+            .add_resource(&FluentName::US_ENGLISH, "wild-index = V")
+            .is_err());
+        assert!(loader
+            .add_resource(&FluentName::DEUTSCH, "king-index = R")
+            .is_ok());
+    }
+
+    #[test]
+    fn add_resource__invalid() {
+        let loader = LayeredLoader::default();
+
+        assert!(matches!(
+            loader.add_resource(&FluentName::US_ENGLISH, "ace-long ="),
+            Err(CardError::InvalidFluentResource(_))
+        ));
+        assert!(matches!(
+            loader.add_file(&FluentName::US_ENGLISH, "no/such/file.ftl"),
+            Err(CardError::InvalidFluentResource(_))
+        ));
+    }
+
+    #[test]
+    fn add_dir() {
+        let loader = LayeredLoader::default();
+        loader.add_dir("./src/localization/locales").unwrap();
+
+        assert_eq!(
+            loader.try_lookup(&FluentName::DEUTSCH, "hearts-long"),
+            Some("Herzen".to_string())
+        );
+        assert_eq!(
+            loader.lookup(&langid!("fr"), "hearts-long"),
+            "Hearts".to_string()
+        );
+    }
+
+    #[test]
+    fn lookup__falls_back_to_locales() {
+        let loader = LayeredLoader::default();
+
+        assert_eq!(loader.lookup(&langid!("de-AT"), "queen-long"), "Dame");
+        assert_eq!(loader.try_lookup(&FluentName::DEUTSCH, "no-such-id"), None);
+    }
+}
@@ -16,7 +16,7 @@ ace-long = Ass
 king-long = König
 queen-long = Dame
 jack-long = Bube
-ten-long = Zhen
+ten-long = Zehn
 nine-long = Neun
 eight-long = Acht
 seven-long = Sieben
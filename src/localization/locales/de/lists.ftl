@@ -0,0 +1,6 @@
+# Lists of cards
+list-separator = {", "}
+list-and = { $head } und { $last }
+
+## A run of cards in the same suit, such as `Ass, König und Dame von Herzen`.
+pile-suit = { $ranks } von { $suit }
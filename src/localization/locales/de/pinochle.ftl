@@ -1,6 +1,6 @@
 # Pinochle Ranks
 pinochle-ace-long = Ass
-pinochle-ten-long = Zhen
+pinochle-ten-long = Zehn
 pinochle-king-long = König
 pinochle-queen-long = Dame
 pinochle-jack-long = Bube
@@ -0,0 +1,6 @@
+# Lists of cards
+list-separator = {", "}
+list-and = { $head } and { $last }
+
+## A run of cards in the same suit, such as `Ace, King and Queen of Hearts`.
+pile-suit = { $ranks } of { $suit }
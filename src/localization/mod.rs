@@ -1,8 +1,18 @@
+pub mod layered;
+
+pub use layered::LayeredLoader;
+
 use crate::types::card_error::CardError;
+use fluent_templates::fluent_bundle::FluentValue;
 use fluent_templates::{langid, static_loader, LanguageIdentifier, Loader};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::str::FromStr;
 use std::string::ToString;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, PoisonError, RwLock};
 
 static_loader! {
     pub static LOCALES = {
@@ -57,12 +67,14 @@ pub trait Named<'a> {
     }
 
     fn fluent_name(&self) -> &FluentName;
-    fn fluent_name_string(&self) -> &String;
+    fn fluent_name_string(&self) -> &'static str;
     fn is_blank(&self) -> bool;
 
     /// This is the core method for getting fluent values. the index, long, and default weight
     /// methods are all just methods simplifying the call to this method.
     ///
+    /// `US_ENGLISH` values are only looked up once for each name, and then cached.
+    ///
     /// ## Usage
     /// ```
     /// use cardpack::localization::*;
@@ -73,8 +85,14 @@ pub trait Named<'a> {
     /// );
     /// ```
     fn fluent_value(&self, key_section: &str, lid: &LanguageIdentifier) -> String {
+        if *lid == Self::US_ENGLISH && FluentName::section(key_section).is_some() {
+            return self.fluent_name().default_value(key_section).to_string();
+        }
+        if let Some(value) = self.fluent_name().defined_value(key_section, lid) {
+            return value.to_string();
+        }
         let id = format!("{}-{}", self.fluent_name_string(), key_section);
-        LOCALES.lookup(lid, id.as_str())
+        LayeredLoader::global().lookup(lid, id.as_str())
     }
 
     /// Returns the value of the `FluentName` index in the fluent templates. An index
@@ -144,7 +162,9 @@ pub trait Named<'a> {
     /// assert_eq!(10, queen.weight());
     /// ```
     fn weight(&self) -> u32 {
-        let weight = self.fluent_value(Self::FLUENT_WEIGHT_SECTION, &Self::US_ENGLISH);
+        let weight = self
+            .fluent_name()
+            .default_value(Self::FLUENT_WEIGHT_SECTION);
         weight.parse().unwrap_or(0)
     }
 
@@ -162,13 +182,19 @@ pub trait Named<'a> {
     /// assert_eq!(31, queen.prime());
     /// ```
     fn prime(&self) -> u32 {
-        let prime = self.fluent_value(Self::FLUENT_PRIME_SECTION, &Self::US_ENGLISH);
+        let prime = self.fluent_name().default_value(Self::FLUENT_PRIME_SECTION);
         prime.parse().unwrap_or(0)
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct FluentName(String);
+/// The name of a card entity in the fluent templates, such as `spades` or `big-joker`.
+///
+/// Names are interned, so a `FluentName` is just a `&'static str` and can be `Copy`. Only the
+/// names that are in the fluent templates, or that have been defined for a
+/// [`DynamicDeck`](crate::decks::dynamic::DynamicDeck), are interned. Any other name is
+/// rejected, so parsing untrusted input can't grow the interned strings.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FluentName(&'static str);
 
 impl FluentName {
     pub const BLANK: &'static str = "blank";
@@ -191,28 +217,219 @@ impl FluentName {
     ///
     /// **NOTE:** there is no perfect way to do this. Empathy is an art form.
     ///
+    /// **Breaking change in 0.6:** a name also has to be in the fluent templates, or added with
+    /// [`LayeredLoader`] or [`FluentName::declare()`]. Any other name becomes `BLANK`, with only a
+    /// logged warning, where before it was kept as is. Code that makes ranks or suits with names
+    /// of its own needs to declare them first.
+    ///
     /// ## Usage
     /// ```
     /// use cardpack::localization::*;
     ///
     /// assert_eq!("spades", FluentName::new("spades").fluent_name_string());
     ///
-    /// // Defaults to `BLANK` when an invalid name is passed in.
+    /// // Defaults to `BLANK` when an invalid name is passed in...
     /// assert_eq!(
     ///   FluentName::BLANK,
     ///   FluentName::new("+++").fluent_name_string()
     /// );
+    ///
+    /// // ...or one that isn't in the fluent templates.
+    /// assert_eq!(
+    ///   FluentName::BLANK,
+    ///   FluentName::new("not-a-card").fluent_name_string()
+    /// );
     /// ```
     #[must_use]
     pub fn new(name_str: &str) -> Self {
-        if Self::is_alphanumeric_hyphen_dash(name_str) {
-            FluentName(name_str.to_string())
-        } else {
-            log::warn!("Invalid name: {} - Defaulting to 'blank'.", name_str);
-            FluentName(Self::BLANK.to_string())
+        match Self::known(name_str) {
+            Ok(name) => name,
+            Err(e) => {
+                log::warn!("{e} - Defaulting to 'blank'.");
+                FluentName(Self::BLANK)
+            }
         }
     }
 
+    /// Adds a name that isn't in the fluent templates, such as the name of a rank or suit in a
+    /// [`DynamicDeck`](crate::decks::dynamic::DynamicDeck), so that it can be used like any other.
+    /// Its index, long name and so on still come from the fluent templates, so they need adding
+    /// with [`LayeredLoader`] for it to be more than a name.
+    ///
+    /// ```
+    /// use cardpack::localization::*;
+    ///
+    /// assert!(FluentName::new("house-rules").is_blank());
+    ///
+    /// let name = FluentName::declare("house-rules").unwrap();
+    ///
+    /// assert_eq!(FluentName::new("house-rules"), name);
+    /// assert!(FluentName::declare("house rules").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentName` if the name isn't alphanumeric with hyphens.
+    pub fn declare(name_str: &str) -> Result<Self, CardError> {
+        if !Self::is_alphanumeric_hyphen_dash(name_str) {
+            return Err(CardError::InvalidFluentName(name_str.to_string()));
+        }
+        let name = intern(name_str);
+        Interned::write().names.insert(name);
+        Ok(FluentName(name))
+    }
+
+    /// Returns the name if it's in the fluent templates, or has been
+    /// [declared](FluentName::declare).
+    fn known(name_str: &str) -> Result<Self, CardError> {
+        if !Self::is_alphanumeric_hyphen_dash(name_str) {
+            return Err(CardError::InvalidFluentName(name_str.to_string()));
+        }
+        if let Some(name) = local(|local| local.names.get(name_str).copied()) {
+            return Ok(FluentName(name));
+        }
+        let declared = Interned::read().names.get(name_str).copied();
+        let name = match declared {
+            Some(name) => name,
+            None if Self::SECTIONS.iter().any(|section| {
+                let id = format!("{name_str}-{section}");
+                LayeredLoader::global()
+                    .try_lookup(&Self::US_ENGLISH, id.as_str())
+                    .is_some()
+            }) =>
+            {
+                let name = intern(name_str);
+                Interned::write().names.insert(name);
+                name
+            }
+            None => return Err(CardError::UnknownFluentName(name_str.to_string())),
+        };
+        local(|local| local.names.insert(name));
+        Ok(FluentName(name))
+    }
+
+    /// The sections that every name can have in the fluent templates.
+    const SECTIONS: [&'static str; 5] = [
+        Self::FLUENT_INDEX_SECTION,
+        Self::FLUENT_LONG_SECTION,
+        Self::FLUENT_SYMBOL_SECTION,
+        Self::FLUENT_WEIGHT_SECTION,
+        Self::FLUENT_PRIME_SECTION,
+    ];
+
+    fn section(key_section: &str) -> Option<&'static str> {
+        Self::SECTIONS
+            .into_iter()
+            .find(|section| *section == key_section)
+    }
+
+    /// Returns the `US_ENGLISH` value for the section of the name, only going to the fluent
+    /// templates the first time it's asked for. The section must be one of the
+    /// [`SECTIONS`](FluentName::SECTIONS), so that the cache can't grow past them.
+    pub(crate) fn default_value(&self, key_section: &str) -> &'static str {
+        let Some(section) = Self::section(key_section) else {
+            log::warn!("Invalid section: {key_section} - Defaulting to ''.");
+            return "";
+        };
+        let key = (self.0, section);
+        if let Some(value) = local(|local| local.values.get(&key).copied()) {
+            return value;
+        }
+        let cached = Interned::read().values.get(&key).copied();
+        let value = cached.unwrap_or_else(|| {
+            let id = format!("{}-{}", self.0, section);
+            let value = intern(&LayeredLoader::global().lookup(&Self::US_ENGLISH, id.as_str()));
+            Interned::write().values.insert(key, value);
+            value
+        });
+        local(|local| local.values.insert(key, value));
+        value
+    }
+
+    /// Sets the value for the section of the name at runtime, such as for the names in a
+    /// [`DynamicDeck`](crate::decks::dynamic::DynamicDeck) that aren't in the fluent templates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidDefinition` if the fluent templates, or an earlier call, already
+    /// have a different value for it, so that a runtime deck can't change how the built in decks,
+    /// or the other runtime decks, look.
+    pub(crate) fn define(
+        &self,
+        key_section: &str,
+        lid: &LanguageIdentifier,
+        value: &str,
+    ) -> Result<(), CardError> {
+        let section = Self::check_definition(self.0, key_section, lid, value)?;
+        let id = format!("{}-{}", self.0, section);
+        let key = (intern(&lid.to_string()), self.0, section);
+        let value = intern(value);
+        let mut interned = Interned::write();
+        match interned.defined.get(&key) {
+            Some(existing) if *existing != value => {
+                return Err(CardError::InvalidDefinition(format!(
+                    "`{id}` is already `{existing}` for {lid}"
+                )));
+            }
+            Some(_) => return Ok(()),
+            None => interned.defined.insert(key, value),
+        };
+        if *lid == Self::US_ENGLISH {
+            interned.values.insert((self.0, section), value);
+            GENERATION.fetch_add(1, Ordering::Release);
+        }
+        Ok(())
+    }
+
+    /// Returns the error that [`define()`](FluentName::define) would for the name, without
+    /// declaring or defining anything, along with the section.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentName` if the name isn't alphanumeric with hyphens, or
+    /// `CardError::InvalidDefinition` if the section isn't one of the
+    /// [`SECTIONS`](FluentName::SECTIONS), or it already has a different value.
+    pub(crate) fn check_definition(
+        name_str: &str,
+        key_section: &str,
+        lid: &LanguageIdentifier,
+        value: &str,
+    ) -> Result<&'static str, CardError> {
+        if !Self::is_alphanumeric_hyphen_dash(name_str) {
+            return Err(CardError::InvalidFluentName(name_str.to_string()));
+        }
+        let Some(section) = Self::section(key_section) else {
+            return Err(CardError::InvalidDefinition(format!(
+                "`{key_section}` isn't a section"
+            )));
+        };
+        let id = format!("{name_str}-{section}");
+        let lid_str = lid.to_string();
+        let existing = LayeredLoader::global()
+            .try_lookup(lid, id.as_str())
+            .or_else(|| {
+                let interned = Interned::read();
+                let defined = interned.defined.get(&(lid_str.as_str(), name_str, section));
+                defined.map(ToString::to_string)
+            });
+        match existing {
+            Some(existing) if existing != value => Err(CardError::InvalidDefinition(format!(
+                "`{id}` is already `{existing}` for {lid}"
+            ))),
+            _ => Ok(section),
+        }
+    }
+
+    /// Returns the value set by [`define()`](FluentName::define) for a language other than
+    /// `US_ENGLISH`, if there is one.
+    fn defined_value(&self, key_section: &str, lid: &LanguageIdentifier) -> Option<&'static str> {
+        let interned = Interned::read();
+        interned
+            .defined
+            .get(&(lid.to_string().as_str(), self.0, key_section))
+            .copied()
+    }
+
     fn is_alphanumeric_hyphen_dash(s: &str) -> bool {
         s.chars()
             .all(|c| c.is_alphanumeric() || c == '-' || c == '–' || c == '—')
@@ -221,13 +438,13 @@ impl FluentName {
 
 impl Default for FluentName {
     fn default() -> Self {
-        FluentName(Self::BLANK.to_string())
+        FluentName(Self::BLANK)
     }
 }
 
 impl Display for FluentName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.clone())
+        write!(f, "{}", self.0)
     }
 }
 
@@ -243,7 +460,7 @@ impl Display for FluentName {
 /// ```
 ///
 /// Unlike `Fluent::new()`, `Fluent::from_str()` will return a `CardError` if the
-/// passed in value is invalid.
+/// passed in value is invalid, or isn't in the fluent templates.
 ///
 /// ```
 /// use std::str::FromStr;
@@ -256,16 +473,17 @@ impl Display for FluentName {
 ///   CardError::InvalidFluentName("Only alphanumeric and hyphens please.".to_string()),
 ///   sut.unwrap_err()
 /// );
+///
+/// assert_eq!(
+///   CardError::UnknownFluentName("not-a-card".to_string()),
+///   FluentName::from_str("not-a-card").unwrap_err()
+/// );
 /// ```
 impl FromStr for FluentName {
     type Err = CardError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if Self::is_alphanumeric_hyphen_dash(s) {
-            Ok(FluentName(s.to_string()))
-        } else {
-            Err(CardError::InvalidFluentName(s.to_string()))
-        }
+        Self::known(s)
     }
 }
 
@@ -278,8 +496,8 @@ impl Named<'_> for FluentName {
         self
     }
 
-    fn fluent_name_string(&self) -> &String {
-        &self.0
+    fn fluent_name_string(&self) -> &'static str {
+        self.0
     }
 
     fn is_blank(&self) -> bool {
@@ -287,6 +505,163 @@ impl Named<'_> for FluentName {
     }
 }
 
+/// The strings that have been interned, along with the known names, the cached `US_ENGLISH`
+/// fluent values for each name and section, the values defined at runtime for each language,
+/// and the card indexes made by joining them. Every one of them comes from the fluent templates
+/// or a deck's definition, never from parsed input, so they can live for the rest of the program.
+#[derive(Default)]
+struct Interned {
+    strings: HashSet<&'static str>,
+    names: HashSet<&'static str>,
+    values: HashMap<(&'static str, &'static str), &'static str>,
+    defined: HashMap<(&'static str, &'static str, &'static str), &'static str>,
+    joined: HashMap<(&'static str, &'static str), &'static str>,
+}
+
+impl Interned {
+    fn lock() -> &'static RwLock<Interned> {
+        static INTERNED: OnceLock<RwLock<Interned>> = OnceLock::new();
+        INTERNED.get_or_init(RwLock::default)
+    }
+
+    fn read() -> std::sync::RwLockReadGuard<'static, Interned> {
+        Self::lock().read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write() -> std::sync::RwLockWriteGuard<'static, Interned> {
+        Self::lock().write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The fluent ids of the cached `US_ENGLISH` values, such as `queen-long`.
+    fn cached_ids() -> Vec<String> {
+        Self::read()
+            .values
+            .keys()
+            .map(|(name, section)| format!("{name}-{section}"))
+            .collect()
+    }
+
+    /// Drops the cached `US_ENGLISH` values for the fluent ids, so that they're looked up again.
+    fn forget(ids: &[String]) {
+        if ids.is_empty() {
+            return;
+        }
+        Self::write()
+            .values
+            .retain(|(name, section), _| !ids.contains(&format!("{name}-{section}")));
+        GENERATION.fetch_add(1, Ordering::Release);
+    }
+}
+
+/// Bumped whenever a cached value is changed or dropped, so that each thread throws away its
+/// [`Local`] copy of them.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// A thread's copy of what it's already read from [`Interned`], so that building a card only
+/// takes the global lock the first time that the thread sees its names.
+#[derive(Default)]
+struct Local {
+    generation: u64,
+    names: HashSet<&'static str>,
+    values: HashMap<(&'static str, &'static str), &'static str>,
+    joined: HashMap<(&'static str, &'static str), &'static str>,
+}
+
+thread_local! {
+    static LOCAL: RefCell<Local> = RefCell::default();
+}
+
+/// Runs `f` on the thread's [`Local`] cache, after emptying it if anything has changed since it
+/// was filled.
+fn local<T>(f: impl FnOnce(&mut Local) -> T) -> T {
+    LOCAL.with(|local| {
+        let mut local = local.borrow_mut();
+        let generation = GENERATION.load(Ordering::Acquire);
+        if local.generation != generation {
+            *local = Local {
+                generation,
+                ..Local::default()
+            };
+        }
+        f(&mut local)
+    })
+}
+
+/// Returns the two interned strings joined together, such as a card's index from its rank and suit
+/// indexes, without allocating once they've been joined before.
+pub(crate) fn concat(a: &'static str, b: &'static str) -> &'static str {
+    if let Some(joined) = local(|local| local.joined.get(&(a, b)).copied()) {
+        return joined;
+    }
+    let cached = Interned::read().joined.get(&(a, b)).copied();
+    let joined = cached.unwrap_or_else(|| {
+        let joined = intern(&format!("{a}{b}"));
+        Interned::write().joined.insert((a, b), joined);
+        joined
+    });
+    local(|local| local.joined.insert((a, b), joined));
+    joined
+}
+
+/// Returns the fluent message for the language, filled in with the passed in arguments, such as
+/// `pile-suit` with the `ranks` and `suit` of a group of cards. The messages are only ever
+/// shown as plain text, and get nested inside each other, so the bidi isolation marks that
+/// fluent puts around each argument are taken back out. Every other message keeps them.
+pub(crate) fn message(
+    lid: &LanguageIdentifier,
+    id: &str,
+    args: &[(&'static str, String)],
+) -> String {
+    let args: HashMap<Cow<'static, str>, FluentValue> = args
+        .iter()
+        .map(|(key, value)| (Cow::Borrowed(*key), FluentValue::from(value.clone())))
+        .collect();
+    LayeredLoader::global()
+        .lookup_with_args(lid, id, &args)
+        .replace(['\u{2068}', '\u{2069}'], "")
+}
+
+/// Joins the items into a list the way that the language writes them, such as
+/// `Ace, King and Queen` in English, or `Ass, König und Dame` in German.
+///
+/// ```
+/// use cardpack::localization::*;
+///
+/// let items = vec!["Ass".to_string(), "König".to_string(), "Dame".to_string()];
+///
+/// assert_eq!(list(&FluentName::DEUTSCH, &items), "Ass, König und Dame");
+/// assert_eq!(list(&FluentName::US_ENGLISH, &items[..2]), "Ass and König");
+/// ```
+#[must_use]
+pub fn list(lid: &LanguageIdentifier, items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [head @ .., last] => message(
+            lid,
+            "list-and",
+            &[
+                ("head", head.join(&message(lid, "list-separator", &[]))),
+                ("last", last.clone()),
+            ],
+        ),
+    }
+}
+
+/// Returns a `'static` copy of the string, leaking it the first time that it's seen.
+pub(crate) fn intern(s: &str) -> &'static str {
+    if let Some(interned) = Interned::read().strings.get(s) {
+        return interned;
+    }
+    let mut interned = Interned::write();
+    if let Some(existing) = interned.strings.get(s) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+    interned.strings.insert(leaked);
+    leaked
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod fluent_tests {
@@ -294,7 +669,7 @@ mod fluent_tests {
 
     #[test]
     fn new() {
-        assert_eq!(FluentName("queen".to_string()), FluentName::new("queen"));
+        assert_eq!(FluentName("queen"), FluentName::new("queen"));
     }
 
     #[test]
@@ -343,10 +718,20 @@ mod fluent_tests {
         );
     }
 
+    #[test]
+    fn new__unknown() {
+        assert!(FluentName::new("not-a-card").is_blank());
+        assert_eq!(
+            CardError::UnknownFluentName("not-a-card".to_string()),
+            FluentName::from_str("not-a-card").unwrap_err()
+        );
+        assert!(!Interned::read().strings.contains("not-a-card"));
+    }
+
     #[test]
     fn named__is_blank() {
         assert!(FluentName::new("blank").is_blank());
-        assert!(!FluentName::new("long").is_blank());
+        assert!(!FluentName::new("queen").is_blank());
     }
 
     #[test]
@@ -367,4 +752,12 @@ mod fluent_tests {
         assert_eq!("S", FluentName::new("spades").index_default());
         assert_eq!("P", FluentName::new("pentacles").index_default());
     }
+
+    #[test]
+    fn intern() {
+        let queen = String::from("queen");
+
+        assert!(std::ptr::eq(super::intern(&queen), super::intern("queen")));
+        assert_eq!(FluentName::new(&queen).default_value("weight"), "10");
+    }
 }
@@ -1,4 +1,5 @@
 pub use crate::decks::canasta::Canasta;
+pub use crate::decks::dynamic::DynamicDeck;
 pub use crate::decks::euchre24::Euchre24;
 pub use crate::decks::french::French;
 pub use crate::decks::french::FrenchCard;
@@ -21,16 +22,27 @@ pub use crate::localization::Named;
 
 pub use crate::types::card::Card;
 pub use crate::types::card_error::CardError;
+pub use crate::types::card_set::CardSet;
+pub use crate::types::dealer::{DealStep, Dealer, Hands};
+pub use crate::types::deck_builder::{CustomDeck, DeckBuilder, DeckStep};
+pub use crate::types::diagnostic::{Diagnostic, Diagnostics, ParseReason};
 pub use crate::types::pile::Pile;
 pub use crate::types::rank::Rank;
+pub use crate::types::rank_profile::RankProfile;
 pub use crate::types::suit::Suit;
-pub use crate::types::traits::{Decked, Ranked, Suited};
+pub use crate::types::traits::{
+    CardOrdering, Decked, Encoded, Literal, Notation, Ranked, Shufflable, Suited,
+};
+pub use crate::types::utils::Literals;
+#[cfg(feature = "derive")]
+pub use cardpack_derive::{Decked, Ranked, Suited};
 
 // Macros
 pub use crate::card;
 pub use crate::cards;
 pub use crate::modern;
 pub use crate::modern_card;
+pub use crate::pile;
 pub use crate::pinochle_card;
 pub use crate::skat;
 pub use crate::skat_card;
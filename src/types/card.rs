@@ -1,9 +1,14 @@
-use crate::localization::Named;
+use crate::decks::french::French;
+use crate::localization::{self, FluentName, Named};
 use crate::types::card_error::CardError;
+use crate::types::notation::Flexible;
 use crate::types::rank::Rank;
 use crate::types::suit::Suit;
-use crate::types::traits::{Ranked, Suited};
+use crate::types::traits::{Notation, Ranked, Suited};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
 use colored::Colorize;
 use fluent_templates::LanguageIdentifier;
@@ -14,8 +19,9 @@ use std::str::FromStr;
 ///
 /// Each card is made up of the following fields:
 ///
-/// - `weight`: A `u32` that is used to sort the cards. It is determined by `(suit.weight * 1000) + rank.weight`.
-/// - `index`: A `String` that is the identity indicator in the corner of a playing card, such as `AS` for ace of spades.
+/// - `weight`: A `u32` that is used to sort the cards. It is determined by `(suit.weight * 1000) + rank.weight`,
+///   but can be overridden, for example for the wild cards in [`Canasta`](crate::decks::canasta::Canasta).
+/// - `index`: A `&'static str` that is the identity indicator in the corner of a playing card, such as `AS` for ace of spades.
 /// - `suit`: A [`Suit`] that implements the [`Suited`] trait.
 /// - `rank`: A [`Rank`] that implements the [`Ranked`] trait.
 ///
@@ -23,7 +29,7 @@ use std::str::FromStr;
 /// that determines the card's
 ///
 /// The goal of this structure is to allow for the flexibility in how decks of cards are represented.
-/// For example, a standard 52 card deck would use the [`French`](crate::decks::french::French)
+/// For example, a standard 52 card deck would use the [`French`]
 /// _unit-like struct_.
 ///
 /// ```
@@ -48,7 +54,28 @@ use std::str::FromStr;
 /// let pile = cards!("KS QC");
 /// assert_eq!(pile.unwrap().to_string(), "K♠ Q♣");
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+///
+/// What a card _is_ is its rank and suit. The `weight` only affects how a
+/// [`Pile`](crate::types::pile::Pile) sorts it, so two cards with the same rank and suit are
+/// equal and hash the same even if one of them has been reweighted. `Card`'s own [`Ord`] is by
+/// suit and then by [`Rank`], and ignores the card's `weight`, so it can differ from the order
+/// that [`Pile::sort()`](crate::types::pile::Pile::sort) puts cards in. Games that need to sort
+/// cards in other ways can pass a [`CardOrdering`](crate::types::traits::CardOrdering) to
+/// [`Pile::sort_by_ordering()`](crate::types::pile::Pile::sort_by_ordering).
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let two_of_spades = Card::<Modern, Modern>::from_str("2S").unwrap();
+///
+/// assert_eq!(Canasta::two_of_spades(), two_of_spades);
+/// assert_ne!(Canasta::two_of_spades().weight, two_of_spades.weight);
+/// ```
+///
+/// A `Card` is `Copy` whenever its deck types are. Its names and index are interned, and the
+/// fluent values behind them are cached, so making a card doesn't allocate once the first one of
+/// its kind has been made.
+#[derive(Debug, Clone, Copy)]
 pub struct Card<RankType, SuitType>
 where
     RankType: Ranked + Clone,
@@ -57,11 +84,21 @@ where
     /// Used to sort Cards.
     pub weight: u32,
     /// The identity indicator in the corner of a playing card, such as `AS` for ace of spades.
-    pub index: String,
+    pub index: &'static str,
     pub suit: Suit<SuitType>,
     pub rank: Rank<RankType>,
 }
 
+/// The fields of a [`Card`], with the rank's name, weight and prime, and the suit's name and
+/// weight.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CardParts {
+    weight: u32,
+    index: &'static str,
+    rank: (FluentName, u32, u32),
+    suit: (FluentName, u32),
+}
+
 impl<RankType, SuitType> Card<RankType, SuitType>
 where
     RankType: Ranked + Clone,
@@ -75,7 +112,7 @@ where
     ///
     /// let expected: Card<French, French> = Card {
     ///     weight: 4012,
-    ///     index: "AS".to_string(),
+    ///     index: "AS",
     ///     rank: Rank::<French>::from('A'),
     ///     suit: Suit::<French>::from('S'),
     /// };
@@ -97,8 +134,10 @@ where
     }
 
     /// Allows you to override the weight of the card from what's set in the
-    /// [`FluentName`](crate::localization::FluentName) field. Sorting
+    /// [`FluentName`] field. Sorting
     /// of Cards is done based on the weight field, so this is a way to create custom sorts.
+    /// The weight isn't part of the card's identity, so the card is still equal to the one
+    /// returned by [`Card::new()`].
     ///
     /// ```
     /// use cardpack::prelude::*;
@@ -125,6 +164,56 @@ where
         }
     }
 
+    /// Parses the card with the passed in [`Notation`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::types::notation::LongName;
+    ///
+    /// assert_eq!(FrenchCard::from_str_with("Queen of Hearts", &LongName).unwrap(), card!(QH));
+    /// assert_eq!(
+    ///     FrenchCard::from_str_with("QH", &LongName).unwrap_err(),
+    ///     CardError::InvalidIndex("QH".to_string())
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidIndex` if the text isn't a card in the notation.
+    pub fn from_str_with<N: Notation>(s: &str, notation: &N) -> Result<Self, CardError> {
+        let s = s.trim();
+        notation
+            .parse(s)
+            .ok_or_else(|| CardError::InvalidIndex(s.to_string()))
+    }
+
+    /// The card without its deck types, so that it can be kept in a
+    /// [`DeckTable`](crate::types::deck_table::DeckTable) that's shared between threads.
+    pub(crate) fn parts(&self) -> CardParts {
+        CardParts {
+            weight: self.weight,
+            index: self.index,
+            rank: (self.rank.name, self.rank.weight, self.rank.prime),
+            suit: (self.suit.name, self.suit.weight),
+        }
+    }
+
+    /// The card that [`parts()`](Card::parts) came from.
+    pub(crate) fn from_parts(parts: &CardParts) -> Self {
+        let (name, weight, prime) = parts.rank;
+        Card {
+            weight: parts.weight,
+            index: parts.index,
+            rank: Rank {
+                weight,
+                prime,
+                name,
+                phantom_data: PhantomData,
+            },
+            suit: Suit::from_parts(parts.suit.0, parts.suit.1),
+        }
+    }
+
     // Private methods
 
     /// The index is the most boring way to represent a `Card` as a `String` using
@@ -142,10 +231,14 @@ where
     /// assert_eq!(jack_of_diamonds.index, "JD");
     /// assert_eq!(jack_of_diamonds.to_string(), "J♦");
     /// ```
-    fn determine_default_index(suit: &Suit<SuitType>, rank: &Rank<RankType>) -> String {
-        let rank = rank.index_default();
-        let suit = suit.index_default();
-        format!("{rank}{suit}")
+    fn determine_default_index(suit: &Suit<SuitType>, rank: &Rank<RankType>) -> &'static str {
+        let rank = rank
+            .name
+            .default_value(Rank::<RankType>::FLUENT_INDEX_SECTION);
+        let suit = suit
+            .name
+            .default_value(Suit::<SuitType>::FLUENT_INDEX_SECTION);
+        localization::concat(rank, suit)
     }
 
     /// Prioritizes sorting by Suit and then by Rank.
@@ -158,12 +251,38 @@ where
 
     /// Used to generate the `Card`'s binary signature, aka [Cactus Kev](https://suffe.cool/poker/evaluator.html)
     /// numbers.
+    ///
+    /// Cactus Kev numbers only have room for the 13 ranks and 4 suits of a
+    /// [`French`] deck, so the number is worked out from the French rank and suit with the same
+    /// names, whatever deck the card is from. It's `None` for blank cards, and for cards that
+    /// aren't in a French deck, such as the jokers of a [`Modern`](crate::decks::modern::Modern)
+    /// deck, the trumps of a [`Tarot`](crate::decks::tarot::Tarot) one, or the ranks of a
+    /// [`Pinochle`](crate::decks::pinochle::Pinochle) one. Use
+    /// [`Encoded`](crate::types::traits::Encoded) for those.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(card!(KS).get_ckc_number(), Some(0b00001000_00000000_10001011_00100101));
+    /// assert_eq!(Modern::big_joker().get_ckc_number(), None);
+    ///
+    /// // The same card in another deck has the same number.
+    /// let king = Card::<Modern, Modern>::from_str("KS").unwrap();
+    /// assert_eq!(king.get_ckc_number(), card!(KS).get_ckc_number());
+    ///
+    /// // Pinochle has its own ranks, which rank differently to the French ones.
+    /// let king = Card::<Pinochle, French>::from_str("KS").unwrap();
+    /// assert_eq!(king.get_ckc_number(), None);
+    /// ```
     #[must_use]
-    pub fn get_ckc_number(&self) -> u32 {
-        if self.is_blank() {
-            return 0;
-        }
-        self.rank.ckc_number() + self.suit.ckc_number()
+    pub fn get_ckc_number(&self) -> Option<u32> {
+        let rank = French::rank_names()
+            .into_iter()
+            .find(|name| *name == self.rank.name.fluent_name_string())?;
+        let suit = French::suit_names()
+            .into_iter()
+            .find(|name| *name == self.suit.name.fluent_name_string())?;
+        Some(Rank::<French>::new(rank).ckc_number() + Suit::<French>::new(suit).ckc_number())
     }
 
     #[must_use]
@@ -176,6 +295,21 @@ where
         self.rank.name.is_blank() || self.suit.name.is_blank()
     }
 
+    /// The index of the card in the passed in language, which can be parsed back with the
+    /// [`Localized`](crate::types::notation::Localized) notation.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(card!(JH).index_localized(&FluentName::DEUTSCH), "BH");
+    /// assert_eq!(card!(QC).index_localized(&FluentName::DEUTSCH), "DK");
+    /// assert_eq!(card!(QC).index_localized(&FluentName::US_ENGLISH), "QC");
+    /// ```
+    #[must_use]
+    pub fn index_localized(&self, lid: &LanguageIdentifier) -> String {
+        format!("{}{}", self.rank.name.index(lid), self.suit.name.index(lid))
+    }
+
     /// TODO need to add a connector for each `LanguageIdentifier`.
     #[must_use]
     pub fn long(&self, lid: &LanguageIdentifier) -> String {
@@ -208,11 +342,48 @@ where
     }
 }
 
+impl<RankType: Ranked + Clone + PartialEq, SuitType: Suited + Clone + PartialEq> PartialEq
+    for Card<RankType, SuitType>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank && self.suit == other.suit
+    }
+}
+
+impl<RankType: Ranked + Clone + Eq, SuitType: Suited + Clone + Eq> Eq for Card<RankType, SuitType> {}
+
+impl<RankType: Ranked + Clone + Hash, SuitType: Suited + Clone + Hash> Hash
+    for Card<RankType, SuitType>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank.hash(state);
+        self.suit.hash(state);
+    }
+}
+
+impl<RankType: Ranked + Clone + Ord, SuitType: Suited + Clone + Ord> PartialOrd
+    for Card<RankType, SuitType>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<RankType: Ranked + Clone + Ord, SuitType: Suited + Clone + Ord> Ord
+    for Card<RankType, SuitType>
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.suit
+            .cmp(&other.suit)
+            .then_with(|| self.rank.cmp(&other.rank))
+    }
+}
+
 impl<RankType: Ranked + Clone, SuitType: Suited + Clone> Default for Card<RankType, SuitType> {
     fn default() -> Self {
         Card {
             weight: 0,
-            index: "__".to_string(),
+            index: "__",
             rank: Rank::<RankType>::default(),
             suit: Suit::<SuitType>::default(),
         }
@@ -243,21 +414,11 @@ impl<RankType: Ranked + Clone, SuitType: Suited + Clone> FromStr for Card<RankTy
     /// TODO: One thing I would highly recommend is that you draw out a sequence diagram
     /// of your code flows to see just how a bill becomes a low. (I am a bill, and I am only a bill...)
     /// See just how convoluted your code is.
+    ///
+    /// Cards are parsed with the [`Flexible`] notation, so `AS`, `Ace of Spades` and `🂡` are all
+    /// the ace of spades.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        // original was `if s.len() != 2 {`
-        if s.chars().count() != 2 {
-            return Err(CardError::InvalidIndex(s.to_string()));
-        }
-        if let Some(c) = s.chars().next() {
-            let rank = Rank::<RankType>::from(c);
-            if let Some(c) = s.chars().last() {
-                let suit = Suit::<SuitType>::from(c);
-                return Ok(Card::new(rank, suit));
-            }
-        }
-
-        Err(CardError::Fubar)
+        Card::from_str_with(s, &Flexible)
     }
 }
 
@@ -273,7 +434,7 @@ mod types__card__tests {
     fn new() {
         let expected: Card<French, French> = Card {
             weight: 4012,
-            index: "AS".to_string(),
+            index: "AS",
             rank: Rank::<French>::from('A'),
             suit: Suit::<French>::from('S'),
         };
@@ -285,6 +446,24 @@ mod types__card__tests {
         assert_eq!(card, expected);
     }
 
+    #[test]
+    fn get_ckc_number__by_french_names() {
+        use crate::decks::modern::Modern;
+        use crate::decks::pinochle::Pinochle;
+        use crate::decks::tarot::Tarot;
+        use crate::types::traits::Decked;
+
+        for card in Modern::deck().iter().filter(|card| !card.suit.is_special()) {
+            let french = Card::<French, French>::from_str(card.index).unwrap();
+            assert!(card.get_ckc_number().is_some());
+            assert_eq!(card.get_ckc_number(), french.get_ckc_number());
+        }
+        let ace = Card::<Pinochle, French>::from_str("AH").unwrap();
+        assert_eq!(ace.get_ckc_number(), None);
+        assert_eq!(Tarot::deck().draw_first().unwrap().get_ckc_number(), None);
+        assert_eq!(Card::<French, French>::default().get_ckc_number(), None);
+    }
+
     #[test]
     fn get_index_suit_char() {
         assert_eq!(
@@ -301,9 +480,9 @@ mod types__card__tests {
         );
     }
 
-    /// I want to make sure that the weight field in the `Card` struct correctly affects the sorting
-    /// of cards. For Decks of cards, the sort would be in reverse, with the higher weighted `Card`
-    /// coming first.
+    /// A `Card` is ordered by its suit, and then its rank, lowest first, so aces of different suits
+    /// sort by suit alone. Sorting by the cards' `weight`, highest first, is up to
+    /// [`ByWeight`](crate::types::ordering::ByWeight), which is what `Pile::sort()` uses.
     #[test]
     fn test_sort_from_weight() {
         let ace_of_spades = card!("AS");
@@ -311,12 +490,7 @@ mod types__card__tests {
         let ace_of_diamonds = card!("AD");
         let ace_of_clubs = card!("AC");
 
-        let mut cards = vec![
-            ace_of_clubs.clone(),
-            ace_of_hearts.clone(),
-            ace_of_spades.clone(),
-            ace_of_diamonds.clone(),
-        ];
+        let mut cards = [ace_of_clubs, ace_of_hearts, ace_of_spades, ace_of_diamonds];
 
         cards.sort();
 
@@ -326,6 +500,19 @@ mod types__card__tests {
         assert_eq!(cards[3], ace_of_spades);
     }
 
+    #[test]
+    fn eq__reweighted_rank() {
+        let ace = Card::<French, French>::new(
+            Rank::new_with_weight(French::ACE, 0),
+            Suit::new(French::SPADES),
+        );
+        let mut set = std::collections::HashSet::new();
+        set.insert(card!("AS"));
+
+        assert_eq!(ace, card!("AS"));
+        assert!(set.contains(&ace));
+    }
+
     #[test]
     fn to_color_symbol_string__default() {
         let card = card!("AS");
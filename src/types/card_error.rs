@@ -1,3 +1,4 @@
+use crate::types::diagnostic::Diagnostics;
 use thiserror::Error;
 
 #[derive(Error, Debug, Eq, PartialEq)]
@@ -11,6 +12,9 @@ pub enum CardError {
     #[error("Invalid Card Count: `{0}`")]
     InvalidCardCount(usize),
 
+    #[error("Invalid Deck Definition: {0}")]
+    InvalidDefinition(String),
+
     #[error(
         "Invalid FluentName: `{0}`. Must be alphanumeric with hyphens, en-dashes, or em-dashes."
     )]
@@ -19,12 +23,39 @@ pub enum CardError {
     #[error("Invalid Fluent Rank: `{0}`. Must be single char.")]
     InvalidFluentRank(String),
 
+    #[error("Invalid Fluent Resource: {0}")]
+    InvalidFluentResource(String),
+
     #[error("Invalid Index: `{0}`")]
     InvalidIndex(String),
 
+    #[error("Invalid Pile: {0}")]
+    InvalidPile(#[from] Diagnostics),
+
+    #[error("Invalid Position: `{0}` is past the end of the pile")]
+    InvalidPosition(usize),
+
+    #[error("Invalid Seat Count: `{0}`")]
+    InvalidSeatCount(usize),
+
+    #[error("Cards not in deck: `{0}`")]
+    NotInDeck(String),
+
+    #[error("Cards not in deck: `{0}`. Too many copies of cards: `{1}`")]
+    NotInDeckAndTooManyCopies(String, String),
+
+    #[error("Card not in pile: `{0}`")]
+    NotInPile(String),
+
     #[error("Not enough cards: `{0}` missing")]
     NotEnoughCards(usize),
 
     #[error("Too many cards: `{0}` extra")]
     TooManyCards(usize),
+
+    #[error("Too many copies of cards: `{0}`")]
+    TooManyCopies(String),
+
+    #[error("Unknown FluentName: `{0}`. Not in the fluent templates.")]
+    UnknownFluentName(String),
 }
@@ -0,0 +1,411 @@
+use crate::types::card::Card;
+use crate::types::card_error::CardError;
+use crate::types::deck_table::DeckTable;
+use crate::types::pile::Pile;
+use crate::types::suit::Suit;
+use crate::types::traits::{Decked, Ranked, Suited};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{BitAnd, BitOr, Sub};
+
+/// A set of cards stored as the bits of a single `u128`, where each card's bit is its
+/// [ordinal](Decked::ordinal) in the deck of its `RankType`. Inserting, removing and checking for a
+/// card are a single bit operation, and set algebra is one instruction, which makes it a lot
+/// cheaper than a [`Pile`] for analysing hands over millions of deals.
+///
+/// A `CardSet` can hold any deck with up to 128 distinct cards, which covers all of the decks in
+/// the library, including [`Tarot`](crate::decks::tarot::Tarot)'s 78. Like a `HashSet`, it only
+/// holds one copy of each card.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let mut hand = CardSet::<French, French>::try_from(&French::from_str("AS KS QH").unwrap()).unwrap();
+///
+/// assert!(hand.contains(&card!(KS)));
+/// assert!(hand.insert(&card!(2C)));
+/// assert!(!hand.insert(&card!(2C)));
+/// assert!(hand.remove(&card!(QH)));
+/// assert_eq!(hand.len(), 3);
+/// assert_eq!(hand.to_string(), "A♠ K♠ 2♣");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CardSet<
+    RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+    SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+> {
+    bits: u128,
+    phantom_data: PhantomData<(RankType, SuitType)>,
+}
+
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > CardSet<RankType, SuitType>
+{
+    /// The most distinct cards that a `CardSet` can hold.
+    pub const CAPACITY: usize = u128::BITS as usize;
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_bits(0)
+    }
+
+    /// Returns a set with every card in the deck.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(CardSet::<French, French>::all().len(), 52);
+    /// assert_eq!(CardSet::<Modern, Modern>::all().len(), 54);
+    /// assert_eq!(CardSet::<Tarot, Tarot>::all().len(), 78);
+    /// ```
+    #[must_use]
+    pub fn all() -> Self {
+        Self::from_bits(Self::table().all())
+    }
+
+    /// Returns a set with every card in the deck of the passed in suit, which can be used as a
+    /// mask to pull a suit out of a hand.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let spades = CardSet::<French, French>::suit_mask(&Suit::from('S'));
+    ///
+    /// assert_eq!(spades.len(), 13);
+    /// assert_eq!(spades.bits(), 0x1FFF);
+    /// ```
+    #[must_use]
+    pub fn suit_mask(suit: &Suit<SuitType>) -> Self {
+        Self::from_bits(Self::table().suit(&suit.name))
+    }
+
+    /// Creates a set straight from its bits. Bits that don't match a card in the deck are kept, but
+    /// never show up as a card.
+    #[must_use]
+    pub fn from_bits(bits: u128) -> Self {
+        Self {
+            bits,
+            phantom_data: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn bits(&self) -> u128 {
+        self.bits
+    }
+
+    /// Returns `true` if the card is in the set.
+    #[must_use]
+    pub fn contains(&self, card: &Card<RankType, SuitType>) -> bool {
+        Self::bit(card).is_some_and(|bit| self.bits & bit != 0)
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::from_bits(self.bits & !other.bits)
+    }
+
+    /// Adds the card to the set. Returns `false` if the card was already in the set or isn't in
+    /// the deck.
+    pub fn insert(&mut self, card: &Card<RankType, SuitType>) -> bool {
+        match Self::bit(card) {
+            Some(bit) if self.bits & bit == 0 => {
+                self.bits |= bit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_bits(self.bits & other.bits)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    /// Returns the cards in the set in the order of the deck, which is the same as
+    /// [`Pile::sort()`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let pile = French::from_str("2C KS AH AS").unwrap();
+    /// let set = CardSet::try_from(&pile).unwrap();
+    ///
+    /// let cards: Vec<FrenchCard> = set.iter().collect();
+    ///
+    /// assert_eq!(Pile::from(cards), pile.sort());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Card<RankType, SuitType>> {
+        let mut bits = self.bits;
+        std::iter::from_fn(move || {
+            while bits != 0 {
+                let ordinal = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                if let Some(card) = RankType::from_ordinal(ordinal) {
+                    return Some(card);
+                }
+            }
+            None
+        })
+    }
+
+    /// The number of cards in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns the cards in the set that are of the passed in suit.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let hand = CardSet::<French, French>::try_from(&French::from_str("AS KH QS 2D").unwrap()).unwrap();
+    ///
+    /// assert_eq!(hand.of_suit(&Suit::from('S')).to_string(), "A♠ Q♠");
+    /// assert!(hand.of_suit(&Suit::from('C')).is_empty());
+    /// ```
+    #[must_use]
+    pub fn of_suit(&self, suit: &Suit<SuitType>) -> Self {
+        self.intersection(&Self::suit_mask(suit))
+    }
+
+    /// Removes the card from the set. Returns `false` if it wasn't there.
+    pub fn remove(&mut self, card: &Card<RankType, SuitType>) -> bool {
+        match Self::bit(card) {
+            Some(bit) if self.bits & bit != 0 => {
+                self.bits &= !bit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the cards in the set as a [`Pile`], sorted in the order of the deck.
+    #[must_use]
+    pub fn to_pile(&self) -> Pile<RankType, SuitType> {
+        Pile::from(self.iter().collect::<Vec<Card<RankType, SuitType>>>())
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_bits(self.bits | other.bits)
+    }
+
+    // Private methods
+
+    fn bit(card: &Card<RankType, SuitType>) -> Option<u128> {
+        RankType::ordinal(card)
+            .filter(|ordinal| *ordinal < Self::CAPACITY)
+            .map(|ordinal| 1 << ordinal)
+    }
+
+    /// The deck's lookup tables, which are only built the first time that they're needed.
+    fn table() -> &'static DeckTable {
+        DeckTable::of::<RankType, RankType, SuitType>()
+    }
+}
+
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > BitAnd for CardSet<RankType, SuitType>
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > BitOr for CardSet<RankType, SuitType>
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(&rhs)
+    }
+}
+
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > Sub for CardSet<RankType, SuitType>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(&rhs)
+    }
+}
+
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > Display for CardSet<RankType, SuitType>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_pile())
+    }
+}
+
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > From<CardSet<RankType, SuitType>> for Pile<RankType, SuitType>
+{
+    fn from(set: CardSet<RankType, SuitType>) -> Self {
+        set.to_pile()
+    }
+}
+
+/// Duplicate cards in the `Pile` are collapsed into one. Returns `CardError::NotInDeck` if any of
+/// the cards aren't in the deck.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let set = CardSet::<French, French>::try_from(&French::decks(2)).unwrap();
+///
+/// assert_eq!(set, CardSet::all());
+/// assert!(CardSet::try_from(&Pile::from(vec![French::blank()])).is_err());
+/// ```
+impl<
+        RankType: Ranked + Decked<RankType, SuitType> + Ord + Clone + Default + Hash + 'static,
+        SuitType: Suited + Ord + Clone + Default + Hash + 'static,
+    > TryFrom<&Pile<RankType, SuitType>> for CardSet<RankType, SuitType>
+{
+    type Error = CardError;
+
+    fn try_from(pile: &Pile<RankType, SuitType>) -> Result<Self, Self::Error> {
+        let mut set = Self::new();
+        for card in pile.v() {
+            let bit =
+                Self::bit(card).ok_or_else(|| CardError::NotInDeck(card.index.to_string()))?;
+            set.bits |= bit;
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__card_set__tests {
+    use super::*;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::decks::pinochle::Pinochle;
+    use crate::decks::tarot::Tarot;
+    use crate::types::traits::Shufflable;
+    use crate::types::utils::Random;
+    use std::str::FromStr;
+
+    fn french(index: &str) -> CardSet<French, French> {
+        CardSet::try_from(&Pile::<French, French>::from_str(index).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = CardSet::<French, French>::new();
+        let card = Card::<French, French>::from_str("TD").unwrap();
+
+        assert!(!set.contains(&card));
+        assert!(set.insert(&card));
+        assert!(set.contains(&card));
+        assert!(!set.insert(&card));
+        assert_eq!(set.len(), 1);
+        assert!(set.remove(&card));
+        assert!(!set.remove(&card));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn insert__not_in_deck() {
+        let mut set = CardSet::<French, French>::new();
+
+        assert!(!set.insert(&French::blank()));
+        assert!(!set.contains(&French::blank()));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = french("AS KS QS JS");
+        let b = french("QS JS TS 9H");
+
+        assert_eq!((a | b).to_string(), "A♠ K♠ Q♠ J♠ T♠ 9♥");
+        assert_eq!((a & b).to_string(), "Q♠ J♠");
+        assert_eq!((a - b).to_string(), "A♠ K♠");
+        assert_eq!(a.union(&b), a | b);
+        assert!((a & b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+    }
+
+    #[test]
+    fn suit_mask() {
+        let masks: Vec<CardSet<French, French>> = Suit::<French>::suits()
+            .iter()
+            .map(CardSet::suit_mask)
+            .collect();
+
+        assert_eq!(
+            masks.iter().fold(CardSet::new(), |a, b| a | *b),
+            CardSet::all()
+        );
+        assert!(masks.iter().all(|mask| mask.len() == 13));
+        assert!((masks[0] & masks[1]).is_empty());
+    }
+
+    #[test]
+    fn suit_mask__jokers() {
+        let jokers = CardSet::<Modern, Modern>::suit_mask(&Suit::new(Modern::JOKER));
+
+        assert_eq!(jokers.bits(), 0b11);
+        assert_eq!(jokers.to_pile(), Modern::jokers());
+    }
+
+    #[test]
+    fn to_pile__sorted() {
+        let deck = French::deck();
+        let mut shuffled = deck.clone();
+        shuffled.riffles(3, &mut Random::seeded(11));
+
+        let set = CardSet::try_from(&shuffled).unwrap();
+
+        assert_eq!(set.to_pile(), deck);
+        assert_eq!(Pile::from(set), shuffled.sort());
+    }
+
+    #[test]
+    fn try_from__pinochle_duplicates() {
+        let set = CardSet::try_from(&Pinochle::deck()).unwrap();
+
+        assert_eq!(set.len(), 24);
+        assert_eq!(set.to_pile(), Pinochle::distinct());
+    }
+
+    #[test]
+    fn all__tarot() {
+        let set = CardSet::<Tarot, Tarot>::all();
+
+        assert_eq!(set.len(), 78);
+        assert_eq!(set.bits().leading_zeros(), 128 - 78);
+        assert_eq!(set.to_pile(), Tarot::deck());
+    }
+}
@@ -1 +1,419 @@
+use crate::types::card_error::CardError;
+use crate::types::pile::Pile;
+use crate::types::traits::{Ranked, Suited};
+use std::hash::Hash;
 
+/// A single step in the dealing of a hand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DealStep {
+    /// Discard the passed in number of cards from the top of the pile, face down.
+    Burn(usize),
+    /// Deal a packet of the passed in number of cards to every seat, starting with the seat to
+    /// the left of the button and ending with the button.
+    Round(usize),
+    /// Deal the passed in number of cards to the kitty, aka the widow, talon or skat.
+    Kitty(usize),
+}
+
+/// The `Dealer` deals a [`Pile`] out to a table of seats, following a sequence of
+/// [`DealSteps`](DealStep).
+///
+/// Seats are numbered from `0` to `seats - 1`, going clockwise. The seat holding the dealer
+/// button deals, and the seat to its left gets the first card.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let mut deck = French::deck();
+/// let hands = Dealer::new(4).round_robin(13).deal(&mut deck).unwrap();
+///
+/// assert!(deck.is_empty());
+/// assert_eq!(hands.seat(1).unwrap().get(0).unwrap(), &card!(AS));
+///
+/// // The dealer gets the last card:
+/// assert_eq!(hands.seat(0).unwrap().get(12).unwrap(), &card!(2C));
+/// ```
+///
+/// Packets, burns and kitties can be combined into whatever the game calls for. Here's how a
+/// Skat hand is dealt, with 3 cards to each player, 2 to the skat, 4 to each player and 3 to
+/// each player:
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let mut deck = Skat::deck().shuffle();
+/// let hands = Dealer::skat().deal(&mut deck).unwrap();
+///
+/// assert_eq!(hands.len(), 3);
+/// assert_eq!(hands.seat(0).unwrap().len(), 10);
+/// assert_eq!(hands.kitty.len(), 2);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Dealer {
+    seats: usize,
+    button: usize,
+    steps: Vec<DealStep>,
+}
+
+impl Dealer {
+    /// Returns a `Dealer` for a table with the passed in number of seats, the button on seat
+    /// `0`, and no steps.
+    #[must_use]
+    pub fn new(seats: usize) -> Self {
+        Dealer {
+            seats,
+            button: 0,
+            steps: Vec::new(),
+        }
+    }
+
+    /// 4 seats, 13 cards each, one at a time.
+    #[must_use]
+    pub fn bridge() -> Self {
+        Dealer::new(4).round_robin(13)
+    }
+
+    /// 4 seats, dealt in packets of 3 and then 2, with the remaining 4 cards going to the kitty.
+    #[must_use]
+    pub fn euchre() -> Self {
+        Dealer::new(4).packets(&[3, 2]).kitty(4)
+    }
+
+    /// 3 seats, dealt 3, then 2 to the skat, then 4, then 3.
+    #[must_use]
+    pub fn skat() -> Self {
+        Dealer::new(3).packets(&[3]).kitty(2).packets(&[4, 3])
+    }
+
+    /// Adds a [`DealStep::Burn`] step.
+    #[must_use]
+    pub fn burn(self, n: usize) -> Self {
+        self.step(DealStep::Burn(n))
+    }
+
+    /// Adds a [`DealStep::Kitty`] step.
+    #[must_use]
+    pub fn kitty(self, n: usize) -> Self {
+        self.step(DealStep::Kitty(n))
+    }
+
+    /// Adds a [`DealStep::Round`] step for each packet size passed in.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let dealer = Dealer::new(4).packets(&[3, 2]);
+    ///
+    /// assert_eq!(dealer.steps(), &[DealStep::Round(3), DealStep::Round(2)]);
+    /// ```
+    #[must_use]
+    pub fn packets(self, sizes: &[usize]) -> Self {
+        sizes
+            .iter()
+            .fold(self, |dealer, &n| dealer.step(DealStep::Round(n)))
+    }
+
+    /// Adds `n` rounds of one card each.
+    #[must_use]
+    pub fn round_robin(self, n: usize) -> Self {
+        self.packets(&vec![1; n])
+    }
+
+    /// Adds a step to the end of the deal.
+    #[must_use]
+    pub fn step(mut self, step: DealStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Moves the dealer button to the passed in seat, wrapping around the table.
+    #[must_use]
+    pub fn with_button(mut self, seat: usize) -> Self {
+        self.button = self.wrap(seat);
+        self
+    }
+
+    /// Returns the seat with the dealer button.
+    #[must_use]
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    /// Returns the seat that receives the first card.
+    #[must_use]
+    pub fn first_seat(&self) -> usize {
+        self.wrap(self.button + 1)
+    }
+
+    /// Passes the dealer button to the next seat, clockwise.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let mut dealer = Dealer::new(3).with_button(2);
+    /// dealer.rotate_button();
+    ///
+    /// assert_eq!(dealer.button(), 0);
+    /// assert_eq!(dealer.first_seat(), 1);
+    /// ```
+    pub fn rotate_button(&mut self) {
+        self.button = self.first_seat();
+    }
+
+    /// Returns the number of cards that the deal needs.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(Dealer::bridge().required(), 52);
+    /// assert_eq!(Dealer::euchre().required(), 24);
+    /// assert_eq!(Dealer::skat().required(), 32);
+    /// ```
+    #[must_use]
+    pub fn required(&self) -> usize {
+        self.steps
+            .iter()
+            .map(|step| match step {
+                DealStep::Burn(n) | DealStep::Kitty(n) => *n,
+                DealStep::Round(n) => n * self.seats,
+            })
+            .sum()
+    }
+
+    #[must_use]
+    pub fn seats(&self) -> usize {
+        self.seats
+    }
+
+    #[must_use]
+    pub fn steps(&self) -> &[DealStep] {
+        &self.steps
+    }
+
+    /// Deals the cards from the top of the passed in `Pile`. Any cards not needed by the deal
+    /// are left in the `Pile`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidSeatCount` if the table has no seats, and
+    /// `CardError::NotEnoughCards` if the `Pile` is too small for the deal, in which case
+    /// the `Pile` isn't touched.
+    pub fn deal<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    >(
+        &self,
+        pile: &mut Pile<RankType, SuitType>,
+    ) -> Result<Hands<RankType, SuitType>, CardError> {
+        if self.seats == 0 {
+            return Err(CardError::InvalidSeatCount(self.seats));
+        }
+        let required = self.required();
+        if pile.len() < required {
+            return Err(CardError::NotEnoughCards(required - pile.len()));
+        }
+
+        let mut hands = Hands::new(self.seats, self.button);
+        for step in &self.steps {
+            match step {
+                DealStep::Burn(n) => hands.burned.extend(&pile.draw(*n)),
+                DealStep::Kitty(n) => hands.kitty.extend(&pile.draw(*n)),
+                DealStep::Round(n) => {
+                    for offset in 0..self.seats {
+                        let seat = self.wrap(self.first_seat() + offset);
+                        hands.seats[seat].extend(&pile.draw(*n));
+                    }
+                }
+            }
+        }
+
+        Ok(hands)
+    }
+
+    fn wrap(&self, seat: usize) -> usize {
+        match self.seats {
+            0 => 0,
+            _ => seat % self.seats,
+        }
+    }
+}
+
+/// The results of a [`Dealer`] deal.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Hands<
+    RankType: Ranked + Ord + Clone + Default + Hash,
+    SuitType: Suited + Ord + Clone + Default + Hash,
+> {
+    /// The seat with the dealer button at the time of the deal.
+    pub button: usize,
+    /// The hands, indexed by seat.
+    pub seats: Vec<Pile<RankType, SuitType>>,
+    pub kitty: Pile<RankType, SuitType>,
+    pub burned: Pile<RankType, SuitType>,
+}
+
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > Hands<RankType, SuitType>
+{
+    fn new(seats: usize, button: usize) -> Self {
+        Hands {
+            button,
+            seats: vec![Pile::default(); seats],
+            kitty: Pile::default(),
+            burned: Pile::default(),
+        }
+    }
+
+    /// Returns true if there are no seats.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.seats.is_empty()
+    }
+
+    /// Returns the number of seats.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.seats.len()
+    }
+
+    /// Returns the hand for the passed in seat.
+    #[must_use]
+    pub fn seat(&self, seat: usize) -> Option<&Pile<RankType, SuitType>> {
+        self.seats.get(seat)
+    }
+
+    /// Returns a copy of the `Hands` with every hand, the kitty and the burned cards sorted.
+    #[must_use]
+    pub fn sort(&self) -> Self {
+        Hands {
+            button: self.button,
+            seats: self.seats.iter().map(Pile::sort).collect(),
+            kitty: self.kitty.sort(),
+            burned: self.burned.sort(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__dealer__tests {
+    use super::*;
+    use crate::decks::euchre24::Euchre24;
+    use crate::decks::french::French;
+    use crate::types::traits::Decked;
+    use std::str::FromStr;
+
+    #[test]
+    fn deal__round_robin() {
+        let mut pile = Pile::<French, French>::from_str("AS KS QS JS TS 9S").unwrap();
+
+        let hands = Dealer::new(3).round_robin(2).deal(&mut pile).unwrap();
+
+        assert_eq!(hands.seat(1).unwrap().to_string(), "A♠ J♠");
+        assert_eq!(hands.seat(2).unwrap().to_string(), "K♠ T♠");
+        assert_eq!(hands.seat(0).unwrap().to_string(), "Q♠ 9♠");
+        assert!(pile.is_empty());
+    }
+
+    #[test]
+    fn deal__button() {
+        let mut pile = Pile::<French, French>::from_str("AS KS QS").unwrap();
+
+        let hands = Dealer::new(3)
+            .with_button(1)
+            .round_robin(1)
+            .deal(&mut pile)
+            .unwrap();
+
+        assert_eq!(hands.button, 1);
+        assert_eq!(hands.seat(2).unwrap().to_string(), "A♠");
+        assert_eq!(hands.seat(0).unwrap().to_string(), "K♠");
+        assert_eq!(hands.seat(1).unwrap().to_string(), "Q♠");
+    }
+
+    #[test]
+    fn deal__burn() {
+        let mut pile = Pile::<French, French>::from_str("AS KS QS JS TS").unwrap();
+
+        let hands = Dealer::new(2)
+            .burn(1)
+            .round_robin(1)
+            .burn(1)
+            .deal(&mut pile)
+            .unwrap();
+
+        assert_eq!(hands.burned.to_string(), "A♠ J♠");
+        assert_eq!(hands.seat(1).unwrap().to_string(), "K♠");
+        assert_eq!(hands.seat(0).unwrap().to_string(), "Q♠");
+        assert_eq!(pile.to_string(), "T♠");
+    }
+
+    #[test]
+    fn deal__euchre() {
+        let mut deck = Euchre24::deck();
+
+        let hands = Dealer::euchre().deal(&mut deck).unwrap();
+
+        assert_eq!(hands.len(), 4);
+        for hand in &hands.seats {
+            assert_eq!(hand.len(), 5);
+        }
+        assert_eq!(hands.kitty.to_string(), "Q♣ J♣ T♣ 9♣");
+        assert_eq!(hands.seat(1).unwrap().to_string(), "A♠ K♠ Q♠ A♦ K♦");
+        assert_eq!(hands.seat(0).unwrap().to_string(), "J♥ T♥ 9♥ A♣ K♣");
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn deal__not_enough_cards() {
+        let mut pile = Pile::<French, French>::from_str("AS KS QS").unwrap();
+
+        let result = Dealer::new(2).round_robin(2).deal(&mut pile);
+
+        assert_eq!(result.unwrap_err(), CardError::NotEnoughCards(1));
+        assert_eq!(pile.len(), 3);
+    }
+
+    #[test]
+    fn deal__no_seats() {
+        let mut deck = French::deck();
+
+        let result = Dealer::new(0).round_robin(1).deal(&mut deck);
+
+        assert_eq!(result.unwrap_err(), CardError::InvalidSeatCount(0));
+    }
+
+    #[test]
+    fn rotate_button() {
+        let mut dealer = Dealer::new(4);
+
+        assert_eq!(dealer.button(), 0);
+        dealer.rotate_button();
+        assert_eq!(dealer.button(), 1);
+        dealer.rotate_button();
+        dealer.rotate_button();
+        dealer.rotate_button();
+        assert_eq!(dealer.button(), 0);
+    }
+
+    #[test]
+    fn hands__sort() {
+        let mut deck = French::deck().shuffle_seeded(4);
+
+        let hands = Dealer::new(4)
+            .burn(2)
+            .round_robin(10)
+            .kitty(10)
+            .deal(&mut deck)
+            .unwrap()
+            .sort();
+
+        for hand in &hands.seats {
+            assert_eq!(hand, &hand.sort());
+        }
+        assert_eq!(hands.kitty, hands.kitty.sort());
+        assert_eq!(hands.burned, hands.burned.sort());
+    }
+}
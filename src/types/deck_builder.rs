@@ -0,0 +1,414 @@
+use crate::localization;
+use crate::types::card::Card;
+use crate::types::card_error::CardError;
+use crate::types::pile::Pile;
+use crate::types::traits::{Decked, Ranked, Suited};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A single change that a [`DeckBuilder`] makes to its deck.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DeckStep<
+    RankType: Ranked + Ord + Clone + Default + Hash,
+    SuitType: Suited + Ord + Clone + Default + Hash,
+> {
+    /// Adds a copy of the card to the bottom of the deck.
+    Push(Card<RankType, SuitType>),
+    /// Stacks the passed in number of copies of the deck so far on top of each other.
+    Copies(usize),
+    /// Takes every copy of the card out of the deck.
+    Remove(Card<RankType, SuitType>),
+    /// Changes the index of every copy of the card, which is what it's parsed and validated by.
+    Rename(Card<RankType, SuitType>, &'static str),
+    /// Changes the weight of every copy of the card, which is what it's sorted by.
+    Reweight(Card<RankType, SuitType>, u32),
+}
+
+/// The `DeckBuilder` derives a variant deck from a base deck by following a sequence of
+/// [`DeckSteps`](DeckStep), so that a house rule deck doesn't need its own module.
+///
+/// Here's a Euchre deck with a Benny, aka the best bower, added to it:
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let benny = Card::<Euchre24, French>::new(Rank::new(Modern::BIG), Suit::new(Modern::JOKER));
+/// let deck = DeckBuilder::from_decked::<Euchre24>()
+///     .push(benny)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(deck.deck().len(), 25);
+/// assert!(deck.contains(&benny));
+/// assert_eq!(deck.from_str("BJ JS").unwrap().to_string(), "B🃟 J♠");
+/// assert!(deck.from_str("BJ BJ").is_err());
+/// ```
+///
+/// Steps are applied in order when the deck is built, so a card removed before the copies are
+/// stacked is missing from each copy:
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let deck = DeckBuilder::from_decked::<Modern>()
+///     .remove(Modern::little_joker())
+///     .copies(2)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(deck.deck().len(), 106);
+/// assert_eq!(
+///     deck.validate(&Modern::from_str("LJ").unwrap()),
+///     Err(CardError::NotInDeck("LJ".to_string()))
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DeckBuilder<
+    RankType: Ranked + Ord + Clone + Default + Hash,
+    SuitType: Suited + Ord + Clone + Default + Hash,
+> {
+    base: Pile<RankType, SuitType>,
+    steps: Vec<DeckStep<RankType, SuitType>>,
+}
+
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > DeckBuilder<RankType, SuitType>
+{
+    /// Returns a `DeckBuilder` that starts from the passed in `Pile`, with no steps.
+    #[must_use]
+    pub fn new(base: Pile<RankType, SuitType>) -> Self {
+        DeckBuilder {
+            base,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Returns a `DeckBuilder` that starts from the [`deck()`](Decked::deck) of the passed in
+    /// type.
+    #[must_use]
+    pub fn from_decked<D: Decked<RankType, SuitType>>() -> Self {
+        DeckBuilder::new(D::deck())
+    }
+
+    /// Adds a [`DeckStep::Push`] step.
+    #[must_use]
+    pub fn push(self, card: Card<RankType, SuitType>) -> Self {
+        self.step(DeckStep::Push(card))
+    }
+
+    /// Adds a [`DeckStep::Copies`] step.
+    #[must_use]
+    pub fn copies(self, n: usize) -> Self {
+        self.step(DeckStep::Copies(n))
+    }
+
+    /// Adds a [`DeckStep::Remove`] step.
+    #[must_use]
+    pub fn remove(self, card: Card<RankType, SuitType>) -> Self {
+        self.step(DeckStep::Remove(card))
+    }
+
+    /// Adds a [`DeckStep::Rename`] step.
+    #[must_use]
+    pub fn rename(self, card: Card<RankType, SuitType>, index: &str) -> Self {
+        self.step(DeckStep::Rename(card, localization::intern(index)))
+    }
+
+    /// Adds a [`DeckStep::Reweight`] step.
+    #[must_use]
+    pub fn reweight(self, card: Card<RankType, SuitType>, weight: u32) -> Self {
+        self.step(DeckStep::Reweight(card, weight))
+    }
+
+    /// Adds a step to the end of the build.
+    #[must_use]
+    pub fn step(mut self, step: DeckStep<RankType, SuitType>) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Returns the steps that the `DeckBuilder` will follow.
+    #[must_use]
+    pub fn steps(&self) -> &[DeckStep<RankType, SuitType>] {
+        &self.steps
+    }
+
+    /// Applies the steps to the base deck, in order.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let result = DeckBuilder::from_decked::<French>()
+    ///     .remove(card!(2C))
+    ///     .remove(card!(2C))
+    ///     .build();
+    ///
+    /// assert_eq!(result, Err(CardError::NotInDeck("2C".to_string())));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `CardError::NotInDeck` if a step refers to a card that isn't in the deck at that point.
+    /// * `CardError::InvalidCard` if a blank card is added.
+    /// * `CardError::InvalidCardCount` if asked for zero copies, or if there are no cards left.
+    /// * `CardError::InvalidIndex` if two different cards end up with the same index.
+    pub fn build(&self) -> Result<CustomDeck<RankType, SuitType>, CardError> {
+        let mut deck = self.base.clone();
+        for step in &self.steps {
+            deck = DeckBuilder::apply(deck, step.clone())?;
+        }
+
+        if deck.is_empty() {
+            return Err(CardError::InvalidCardCount(0));
+        }
+        let mut indexes: HashMap<&str, Card<RankType, SuitType>> = HashMap::new();
+        for card in &deck {
+            if *indexes.entry(card.index).or_insert(card.clone()) != *card {
+                return Err(CardError::InvalidIndex(card.index.to_string()));
+            }
+        }
+
+        Ok(CustomDeck { deck })
+    }
+
+    // Private methods
+
+    fn apply(
+        mut deck: Pile<RankType, SuitType>,
+        step: DeckStep<RankType, SuitType>,
+    ) -> Result<Pile<RankType, SuitType>, CardError> {
+        match step {
+            DeckStep::Push(card) => {
+                let index = card.index;
+                if !deck.push(card) {
+                    return Err(CardError::InvalidCard(index.to_string()));
+                }
+            }
+            DeckStep::Copies(0) => return Err(CardError::InvalidCardCount(0)),
+            DeckStep::Copies(n) => {
+                deck = deck.iter().cloned().cycle().take(deck.len() * n).collect();
+            }
+            DeckStep::Remove(card) => {
+                let before = deck.len();
+                deck = deck.iter().filter(|&c| *c != card).cloned().collect();
+                if deck.len() == before {
+                    return Err(CardError::NotInDeck(card.index.to_string()));
+                }
+            }
+            DeckStep::Rename(card, index) => {
+                DeckBuilder::update(&mut deck, &card, |c| c.index = index)?;
+            }
+            DeckStep::Reweight(card, weight) => {
+                DeckBuilder::update(&mut deck, &card, |c| c.weight = weight)?;
+            }
+        }
+        Ok(deck)
+    }
+
+    fn update(
+        deck: &mut Pile<RankType, SuitType>,
+        card: &Card<RankType, SuitType>,
+        f: impl Fn(&mut Card<RankType, SuitType>),
+    ) -> Result<(), CardError> {
+        let mut found = false;
+        for c in deck.iter_mut().filter(|c| *c == card) {
+            f(c);
+            found = true;
+        }
+        if found {
+            Ok(())
+        } else {
+            Err(CardError::NotInDeck(card.index.to_string()))
+        }
+    }
+}
+
+/// A deck built by a [`DeckBuilder`], along with the rules for which cards belong to it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CustomDeck<
+    RankType: Ranked + Ord + Clone + Default + Hash,
+    SuitType: Suited + Ord + Clone + Default + Hash,
+> {
+    deck: Pile<RankType, SuitType>,
+}
+
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > CustomDeck<RankType, SuitType>
+{
+    /// Returns `true` if the card, or a card with the same index, is in the deck.
+    #[must_use]
+    pub fn contains(&self, card: &Card<RankType, SuitType>) -> bool {
+        self.deck.iter().any(|c| c.index == card.index)
+    }
+
+    /// Returns a fresh copy of the deck.
+    #[must_use]
+    pub fn deck(&self) -> Pile<RankType, SuitType> {
+        self.deck.clone()
+    }
+
+    /// Parses the index into a `Pile` of the deck's cards. Each card is matched by its index or
+    /// how it's displayed in the deck first, so added and renamed cards can be parsed, and
+    /// otherwise parsed as a regular card and given the deck's weight for it.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let deck = DeckBuilder::from_decked::<Modern>()
+    ///     .rename(Modern::big_joker(), "BENNY")
+    ///     .reweight(modern_card!("2C"), 0)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let pile = deck.from_str("BENNY 2C").unwrap();
+    ///
+    /// assert_eq!(deck.from_str(&pile.to_string()).unwrap(), pile);
+    ///
+    /// assert_eq!(pile.to_string(), "B🃟 2♣");
+    /// assert_eq!(pile[1].weight, 0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CardError` if the index can't be parsed, or if the cards aren't valid for the
+    /// deck.
+    pub fn from_str(&self, index: &str) -> Result<Pile<RankType, SuitType>, CardError> {
+        let mut pile = Pile::<RankType, SuitType>::default();
+        for s in index.split_whitespace() {
+            let found = self
+                .deck
+                .iter()
+                .find(|c| c.index == s || c.to_string() == s);
+            let card = if let Some(card) = found {
+                card.clone()
+            } else {
+                let parsed = Card::<RankType, SuitType>::from_str(s)?;
+                self.deck
+                    .iter()
+                    .find(|c| **c == parsed)
+                    .cloned()
+                    .unwrap_or(parsed)
+            };
+            if !pile.push(card) {
+                return Err(CardError::InvalidIndex(s.to_string()));
+            }
+        }
+
+        if pile.is_empty() {
+            return Err(CardError::InvalidIndex(index.to_string()));
+        }
+        self.validate(&pile)?;
+        Ok(pile)
+    }
+
+    /// Validates that the `Pile` could have been dealt from the deck. See
+    /// [`Pile::validate_against()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotInDeck` or `CardError::TooManyCopies` like
+    /// [`Decked::validate()`].
+    pub fn validate(&self, pile: &Pile<RankType, SuitType>) -> Result<(), CardError> {
+        pile.validate_against(&self.deck)
+    }
+}
+
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > From<CustomDeck<RankType, SuitType>> for Pile<RankType, SuitType>
+{
+    fn from(deck: CustomDeck<RankType, SuitType>) -> Self {
+        deck.deck
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__deck_builder__tests {
+    use super::*;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::types::rank::Rank;
+    use crate::types::suit::Suit;
+
+    fn card(index: &str) -> Card<Modern, Modern> {
+        Card::from_str(index).unwrap()
+    }
+
+    /// The four handed 500 deck: no twos, threes or black fours, and one joker.
+    #[test]
+    fn build__five_hundred() {
+        let deck = ["2S", "2H", "2D", "2C", "3S", "3H", "3D", "3C", "4S", "4C"]
+            .iter()
+            .fold(DeckBuilder::from_decked::<Modern>(), |builder, index| {
+                builder.remove(card(index))
+            })
+            .remove(Modern::little_joker())
+            .build()
+            .unwrap();
+
+        assert_eq!(deck.deck().len(), 43);
+        assert!(deck.contains(&card("4H")));
+        assert!(!deck.contains(&card("4S")));
+    }
+
+    #[test]
+    fn build__empty() {
+        let builder = DeckBuilder::new(Pile::<French, French>::default());
+
+        assert_eq!(builder.build(), Err(CardError::InvalidCardCount(0)));
+        assert_eq!(
+            builder.copies(0).build(),
+            Err(CardError::InvalidCardCount(0))
+        );
+    }
+
+    #[test]
+    fn build__blank() {
+        let result = DeckBuilder::from_decked::<Modern>()
+            .push(Card::default())
+            .build();
+
+        assert_eq!(result, Err(CardError::InvalidCard("__".to_string())));
+    }
+
+    #[test]
+    fn build__index_clash() {
+        let result = DeckBuilder::from_decked::<Modern>()
+            .rename(Modern::big_joker(), "LJ")
+            .build();
+
+        assert_eq!(result, Err(CardError::InvalidIndex("LJ".to_string())));
+    }
+
+    #[test]
+    fn build__reweight_copies() {
+        let mut deck = DeckBuilder::from_decked::<Modern>()
+            .copies(3)
+            .reweight(card("3H"), 6_000)
+            .build()
+            .unwrap()
+            .deck()
+            .sort();
+
+        assert_eq!(deck.len(), 162);
+        assert_eq!(deck.draw(3).to_string(), "3♥ 3♥ 3♥");
+    }
+
+    #[test]
+    fn steps() {
+        let three = Card::<Modern, Modern>::new(Rank::new(French::THREE), Suit::new(French::CLUBS));
+        let builder = DeckBuilder::from_decked::<Modern>().copies(2).remove(three);
+
+        assert_eq!(
+            builder.steps(),
+            &[DeckStep::Copies(2), DeckStep::Remove(three)]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::localization::FluentName;
+use crate::types::card::{Card, CardParts};
+use crate::types::pile::Pile;
+use crate::types::traits::{Decked, Ranked, Suited};
+use crate::types::utils::PerType;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::OnceLock;
+
+/// The lookup tables for a deck, built from its [`Decked::deck()`] the first time that they're
+/// needed and then kept for the rest of the program, so that a card's
+/// [ordinal](Decked::ordinal), and the [`CardSet`](crate::types::card_set::CardSet) masks, are
+/// a single lookup instead of a walk through a freshly built deck.
+pub(crate) struct DeckTable {
+    /// Every distinct card in the deck, in the order that they first show up in it.
+    cards: Vec<CardParts>,
+    /// The ordinal of each card, by index.
+    ordinals: HashMap<&'static str, usize>,
+    /// The bits of the first 128 cards.
+    all: u128,
+    /// The bits of the first 128 cards of each suit, by suit name.
+    suits: HashMap<FluentName, u128>,
+}
+
+impl DeckTable {
+    /// Returns the tables for the deck. A deck that's empty, such as a
+    /// [`Dynamic`](crate::decks::dynamic::Dynamic) one that hasn't been loaded yet, isn't kept.
+    pub(crate) fn of<DeckType, RankType, SuitType>() -> &'static DeckTable
+    where
+        DeckType: Decked<RankType, SuitType> + 'static,
+        RankType: Ranked + Clone + Hash + Ord + Default + 'static,
+        SuitType: Suited + Clone + Hash + Ord + Default + 'static,
+    {
+        static EMPTY: OnceLock<DeckTable> = OnceLock::new();
+
+        PerType::get_or_init::<(DeckType, RankType, SuitType), DeckTable>(|| {
+            Some(DeckTable::new(&DeckType::deck())).filter(|table| !table.cards.is_empty())
+        })
+        .unwrap_or_else(|| {
+            EMPTY.get_or_init(|| DeckTable::new(&Pile::<RankType, SuitType>::default()))
+        })
+    }
+
+    fn new<RankType, SuitType>(deck: &Pile<RankType, SuitType>) -> DeckTable
+    where
+        RankType: Ranked + Clone + Hash + Ord + Default,
+        SuitType: Suited + Clone + Hash + Ord + Default,
+    {
+        let mut table = DeckTable {
+            cards: Vec::new(),
+            ordinals: HashMap::new(),
+            all: 0,
+            suits: HashMap::new(),
+        };
+        for card in deck {
+            if table.ordinals.contains_key(card.index) {
+                continue;
+            }
+            let ordinal = table.cards.len();
+            if ordinal < u128::BITS as usize {
+                table.all |= 1 << ordinal;
+                *table.suits.entry(card.suit.name).or_default() |= 1 << ordinal;
+            }
+            table.ordinals.insert(card.index, ordinal);
+            table.cards.push(card.parts());
+        }
+        table
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub(crate) fn card<RankType, SuitType>(
+        &self,
+        ordinal: usize,
+    ) -> Option<Card<RankType, SuitType>>
+    where
+        RankType: Ranked + Clone,
+        SuitType: Suited + Clone,
+    {
+        self.cards.get(ordinal).map(Card::from_parts)
+    }
+
+    pub(crate) fn ordinal<RankType, SuitType>(
+        &self,
+        card: &Card<RankType, SuitType>,
+    ) -> Option<usize>
+    where
+        RankType: Ranked + Clone,
+        SuitType: Suited + Clone,
+    {
+        self.ordinals.get(card.index).copied()
+    }
+
+    /// The bits of every card in the deck, up to the first 128.
+    pub(crate) fn all(&self) -> u128 {
+        self.all
+    }
+
+    /// The bits of every card in the deck of the suit, up to the first 128.
+    pub(crate) fn suit(&self, name: &FluentName) -> u128 {
+        self.suits.get(name).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,496 @@
+//! Diagnostics for indexes that don't parse. Where [`Pile::from_str_with()`] gives up at the
+//! first bad card with a `CardError::InvalidIndex`, [`Pile::diagnose()`] and
+//! [`Decked::diagnose()`](crate::types::traits::Decked::diagnose) carry on to the end, and say
+//! where each bad card is, what's wrong with it, and what might have been meant. `FromStr` for a
+//! [`Pile`] returns them as a `CardError::InvalidPile`.
+use crate::localization::Named;
+use crate::types::card::Card;
+use crate::types::notation::{Flexible, Index};
+use crate::types::pile::Pile;
+use crate::types::rank::Rank;
+use crate::types::suit::Suit;
+use crate::types::traits::{Notation, Ranked, Suited};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Why a card in an index couldn't be parsed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParseReason {
+    /// There aren't any cards in the index.
+    Empty,
+    /// The card isn't written in any notation that the deck knows, such as `ASK`.
+    Unreadable,
+    /// The rank isn't one of the deck's, such as the `1` of `1S`.
+    UnknownRank(String),
+    /// The suit isn't one of the deck's, such as the `X` of `AX`.
+    UnknownSuit(String),
+    /// The rank and suit are fine, but the deck doesn't have the card, such as `2C` in
+    /// [`Spades`](crate::decks::spades::Spades).
+    NotInDeck,
+    /// There are more copies of the card than the deck has.
+    TooManyCopies,
+}
+
+impl Display for ParseReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseReason::Empty => write!(f, "no cards"),
+            ParseReason::Unreadable => write!(f, "not a card"),
+            ParseReason::UnknownRank(rank) => write!(f, "unknown rank `{rank}`"),
+            ParseReason::UnknownSuit(suit) => write!(f, "unknown suit `{suit}`"),
+            ParseReason::NotInDeck => write!(f, "not in the deck"),
+            ParseReason::TooManyCopies => write!(f, "too many copies"),
+        }
+    }
+}
+
+/// A card in an index that couldn't be parsed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Diagnostic {
+    /// The card as it was written.
+    pub token: String,
+    /// Which card in the index it is, counting from `0`.
+    pub position: usize,
+    /// The bytes of the index that the card takes up.
+    pub span: Range<usize>,
+    pub reason: ParseReason,
+    /// The indexes of the cards that might have been meant, best guess first.
+    pub suggestions: Vec<String>,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` at {}..{}: {}",
+            self.token, self.span.start, self.span.end, self.reason
+        )?;
+        if !self.suggestions.is_empty() {
+            let suggestions: Vec<String> =
+                self.suggestions.iter().map(|s| format!("`{s}`")).collect();
+            write!(f, " (did you mean {}?)", suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Every [`Diagnostic`] for an index, in the order that the cards show up.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let diagnostics = Pile::<French, French>::diagnose("AS 1H KX").unwrap_err();
+///
+/// assert_eq!(
+///     diagnostics.to_string(),
+///     "`1H` at 3..5: unknown rank `1` (did you mean `AH`, `TH`, `2H`?); \
+///      `KX` at 6..8: unknown suit `X` (did you mean `KS`, `KH`, `KD`, `KC`?)"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Diagnostics(Vec<Diagnostic>);
+
+impl Diagnostics {
+    /// How many ranks to suggest for an unknown rank.
+    const RANK_SUGGESTIONS: usize = 3;
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn v(&self) -> &Vec<Diagnostic> {
+        &self.0
+    }
+
+    /// Parses every card in the index with the [`Flexible`] notation, collecting a
+    /// [`Diagnostic`] for each one that fails. If there's a deck, the cards also have to be
+    /// in it, as many times as it has them, and only its cards are suggested. Blank cards,
+    /// from names that aren't in the fluent templates, are never suggested.
+    pub(crate) fn parse<RankType, SuitType>(
+        index: &str,
+        deck: Option<&Pile<RankType, SuitType>>,
+    ) -> Result<Pile<RankType, SuitType>, Diagnostics>
+    where
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    {
+        let mut allowed: HashMap<&str, usize> = HashMap::new();
+        for card in deck.iter().flat_map(|deck| deck.iter()) {
+            *allowed.entry(card.index).or_default() += 1;
+        }
+        let in_deck =
+            |card: &Card<RankType, SuitType>| deck.is_none() || allowed.contains_key(card.index);
+
+        let mut cards = Pile::<RankType, SuitType>::default();
+        let mut diagnostics = Diagnostics::default();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for (position, token) in Flexible.split(index).into_iter().enumerate() {
+            let result = Diagnostics::card::<RankType, SuitType>(token).and_then(|card| {
+                let count = counts.entry(card.index).or_default();
+                *count += 1;
+                match deck.map(|_| allowed.get(card.index)) {
+                    Some(None) => Err((ParseReason::NotInDeck, Diagnostics::near(&card, deck))),
+                    Some(Some(max)) if *count > *max => Err((ParseReason::TooManyCopies, vec![])),
+                    _ => Ok(card),
+                }
+            });
+            match result {
+                Ok(card) => {
+                    cards.push(card);
+                }
+                Err((reason, suggestions)) => diagnostics.0.push(Diagnostic {
+                    token: token.to_string(),
+                    position,
+                    span: Diagnostics::span(index, token),
+                    reason,
+                    suggestions: suggestions
+                        .iter()
+                        .filter(|card| !card.is_blank() && in_deck(card))
+                        .map(|card| card.index.to_string())
+                        .collect(),
+                }),
+            }
+        }
+
+        if cards.is_empty() && diagnostics.is_empty() {
+            diagnostics.0.push(Diagnostic {
+                token: index.to_string(),
+                position: 0,
+                span: 0..index.len(),
+                reason: ParseReason::Empty,
+                suggestions: vec![],
+            });
+        }
+
+        if diagnostics.is_empty() {
+            Ok(cards)
+        } else {
+            Err(diagnostics)
+        }
+    }
+
+    /// Parses a single card, or works out why it can't be, along with the cards that might
+    /// have been meant.
+    #[allow(clippy::type_complexity)]
+    fn card<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        token: &str,
+    ) -> Result<Card<RankType, SuitType>, (ParseReason, Vec<Card<RankType, SuitType>>)> {
+        if let Some(card) = Flexible.parse::<RankType, SuitType>(token) {
+            if !card.is_blank() {
+                return Ok(card);
+            }
+        }
+
+        // Anything that's still a card at this point is written as an index, with the suit
+        // last. Spaces or a word for the rank mean that it's something else, such as a
+        // misspelt long name.
+        let mut chars = token.chars();
+        let Some(suit_char) = chars.next_back() else {
+            return Err((ParseReason::Unreadable, vec![]));
+        };
+        let rank = chars.as_str().trim_end_matches(Index::SEPARATORS);
+        if rank.is_empty()
+            || rank.contains(char::is_whitespace)
+            || (rank.chars().count() > 2 && rank.contains(|c: char| c.is_ascii_alphabetic()))
+        {
+            return Err((ParseReason::Unreadable, vec![]));
+        }
+
+        match (
+            Index::rank::<RankType>(rank),
+            Index::suit::<SuitType>(suit_char),
+        ) {
+            (Some(rank), None) => Err((
+                ParseReason::UnknownSuit(suit_char.to_string()),
+                Diagnostics::suits(&rank),
+            )),
+            (None, Some(suit)) => Err((
+                ParseReason::UnknownRank(rank.to_string()),
+                Diagnostics::ranks(rank, &suit),
+            )),
+            _ => Err((ParseReason::Unreadable, Diagnostics::swapped(token))),
+        }
+    }
+
+    /// The rank in each of the suits of its tier.
+    fn suits<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        rank: &Rank<RankType>,
+    ) -> Vec<Card<RankType, SuitType>> {
+        let names = if RankType::special_rank_names().contains(&rank.fluent_name_string()) {
+            SuitType::special_suit_names()
+        } else {
+            SuitType::suit_names()
+        };
+        names
+            .into_iter()
+            .map(|name| Card::new(rank.clone(), Suit::new(name)))
+            .collect()
+    }
+
+    /// The suit with the ranks of its tier whose chars are closest to the unknown one. A `1`
+    /// is taken to be an ace.
+    fn ranks<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        rank: &str,
+        suit: &Suit<SuitType>,
+    ) -> Vec<Card<RankType, SuitType>> {
+        let Some(typed) = rank.chars().next().and_then(|c| c.to_uppercase().next()) else {
+            return vec![];
+        };
+        let names = if suit.is_special() {
+            RankType::special_rank_names()
+        } else {
+            RankType::rank_names()
+        };
+        let distance = |name: &&'static str| {
+            RankType::rank_chars()
+                .into_iter()
+                .filter(|c| RankType::rank_for_char(c) == Some(*name))
+                .filter_map(|c| c.to_uppercase().next())
+                .map(|c| {
+                    if typed == '1' && c == 'A' {
+                        0
+                    } else {
+                        (i64::from(u32::from(c)) - i64::from(u32::from(typed))).abs()
+                    }
+                })
+                .min()
+                .unwrap_or(i64::MAX)
+        };
+
+        let mut names = names;
+        names.sort_by_key(distance);
+        names
+            .into_iter()
+            .map(|name| Card::new(Rank::new(name), suit.clone()))
+            .filter(|card| !card.is_blank())
+            .take(Diagnostics::RANK_SUGGESTIONS)
+            .collect()
+    }
+
+    /// The card with its rank and suit the other way around, such as `AS` for `SA`.
+    fn swapped<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        token: &str,
+    ) -> Vec<Card<RankType, SuitType>> {
+        let chars: Vec<char> = token.chars().collect();
+        match chars[..] {
+            [suit, rank] => Index::rank::<RankType>(&rank.to_string())
+                .zip(Index::suit::<SuitType>(suit))
+                .map(|(rank, suit)| Card::new(rank, suit))
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// The cards in the deck with the same suit, closest in rank first.
+    fn near<RankType, SuitType>(
+        card: &Card<RankType, SuitType>,
+        deck: Option<&Pile<RankType, SuitType>>,
+    ) -> Vec<Card<RankType, SuitType>>
+    where
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    {
+        let mut near: Vec<Card<RankType, SuitType>> = deck
+            .iter()
+            .flat_map(|deck| deck.iter())
+            .filter(|c| c.suit == card.suit)
+            .cloned()
+            .collect();
+        near.sort_by_key(|c| c.rank.weight.abs_diff(card.rank.weight));
+        near.dedup_by(|a, b| a.index == b.index);
+        near.truncate(Diagnostics::RANK_SUGGESTIONS);
+        near
+    }
+
+    /// Where the token is in the index. [`Notation::split()`] hands back slices of the index,
+    /// so it's where the slice starts.
+    fn span(index: &str, token: &str) -> Range<usize> {
+        let start = (token.as_ptr() as usize)
+            .checked_sub(index.as_ptr() as usize)
+            .filter(|start| start + token.len() <= index.len())
+            .or_else(|| index.find(token))
+            .unwrap_or_default();
+        start..start + token.len()
+    }
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let diagnostics: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", diagnostics.join("; "))
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__diagnostic__tests {
+    use super::*;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::decks::pinochle::Pinochle;
+    use crate::decks::spades::Spades;
+    use crate::decks::tarot::Tarot;
+    use crate::types::traits::Decked;
+    use colored::Color;
+
+    /// A French deck with a rank and a suit whose names aren't in the fluent templates.
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    struct Unknown {}
+
+    impl Unknown {
+        const UNKNOWN: &'static str = "diagnostic-test-unknown";
+    }
+
+    impl Ranked for Unknown {
+        fn rank_chars() -> Vec<char> {
+            vec!['A', 'B', 'K']
+        }
+
+        fn rank_names() -> Vec<&'static str> {
+            vec![French::ACE, Unknown::UNKNOWN, French::KING]
+        }
+
+        fn rank_for_char(c: &char) -> Option<&'static str> {
+            match c {
+                'A' => Some(French::ACE),
+                'B' => Some(Unknown::UNKNOWN),
+                'K' => Some(French::KING),
+                _ => None,
+            }
+        }
+
+        fn type_name() -> &'static str {
+            "Unknown"
+        }
+    }
+
+    impl Suited for Unknown {
+        fn colors() -> HashMap<char, Color> {
+            HashMap::new()
+        }
+
+        fn suit_chars() -> Vec<char> {
+            vec!['S', 'U']
+        }
+
+        fn suit_names() -> Vec<&'static str> {
+            vec![French::SPADES, Unknown::UNKNOWN]
+        }
+
+        fn suit_for_char(c: &char) -> Option<&'static str> {
+            match c {
+                'S' => Some(French::SPADES),
+                'U' => Some(Unknown::UNKNOWN),
+                _ => None,
+            }
+        }
+
+        fn type_name() -> &'static str {
+            "Unknown"
+        }
+    }
+
+    fn diagnose(index: &str) -> Vec<Diagnostic> {
+        Pile::<French, French>::diagnose(index)
+            .unwrap_err()
+            .v()
+            .clone()
+    }
+
+    #[test]
+    fn diagnose__ok() {
+        assert_eq!(
+            Pile::<French, French>::diagnose("AS, 10h, Queen of Diamonds")
+                .unwrap()
+                .to_string(),
+            "A♠ T♥ Q♦"
+        );
+    }
+
+    #[test]
+    fn diagnose__every_error() {
+        let diagnostics = diagnose("AS BS KX ASK SA");
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.position, d.span.clone(), d.reason.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, 3..5, ParseReason::UnknownRank("B".to_string())),
+                (2, 6..8, ParseReason::UnknownSuit("X".to_string())),
+                (3, 9..12, ParseReason::Unreadable),
+                (4, 13..15, ParseReason::Unreadable),
+            ]
+        );
+        assert_eq!(diagnostics[0].suggestions, vec!["AS", "JS", "KS"]);
+        assert_eq!(diagnostics[3].suggestions, vec!["AS"]);
+    }
+
+    #[test]
+    fn diagnose__spans_are_bytes() {
+        let diagnostics = diagnose("[A♠, 1♥]");
+
+        assert_eq!(diagnostics[0].token, "1♥");
+        assert_eq!(diagnostics[0].position, 1);
+        assert_eq!(diagnostics[0].span, 7..11);
+        assert_eq!(&"[A♠, 1♥]"[diagnostics[0].span.clone()], "1♥");
+    }
+
+    #[test]
+    fn diagnose__empty() {
+        assert_eq!(diagnose(" ")[0].reason, ParseReason::Empty);
+    }
+
+    #[test]
+    fn diagnose__tiers() {
+        let diagnostics = Pile::<Modern, Modern>::diagnose("BX").unwrap_err();
+        assert_eq!(diagnostics.v()[0].suggestions, vec!["BJ"]);
+
+        let diagnostics = Tarot::diagnose("AM Queen of Diamonds").unwrap_err();
+        assert_eq!(diagnostics.v()[0].reason, ParseReason::NotInDeck);
+        assert_eq!(diagnostics.v()[1].reason, ParseReason::Unreadable);
+    }
+
+    #[test]
+    fn diagnose__no_blank_suggestions() {
+        let diagnostics = Pile::<Unknown, Unknown>::diagnose("AX CS").unwrap_err();
+
+        assert_eq!(diagnostics.v()[0].suggestions, vec!["AS"]);
+        assert_eq!(diagnostics.v()[1].suggestions, vec!["AS", "KS"]);
+    }
+
+    #[test]
+    fn decked__diagnose() {
+        let diagnostics = Spades::diagnose("2S 2C AS").unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics.v()[0].reason, ParseReason::NotInDeck);
+        assert_eq!(diagnostics.v()[0].suggestions, vec!["3C", "4C", "5C"]);
+
+        let diagnostics = Pinochle::diagnose("AS AS AS KS 8S").unwrap_err();
+        assert_eq!(
+            diagnostics
+                .v()
+                .iter()
+                .map(|d| (d.position, d.reason.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, ParseReason::TooManyCopies),
+                (4, ParseReason::UnknownRank("8".to_string()))
+            ]
+        );
+        assert_eq!(diagnostics.v()[1].suggestions, vec!["9S", "TS", "AS"]);
+    }
+}
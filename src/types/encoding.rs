@@ -0,0 +1,262 @@
+use crate::localization::{FluentName, Named};
+use crate::types::card::Card;
+use crate::types::pile::Pile;
+use crate::types::traits::{Ranked, Suited};
+use crate::types::utils::Bit;
+use std::hash::Hash;
+
+/// The layout of a deck-aware binary encoding of a card into a `u64`, generated from the ranks
+/// and suits that show up in the deck. From the lowest bit up, it has:
+///
+/// * [`Encoding::PRIME_BITS`] bits for the rank's prime,
+/// * the rank's number, counting up from `0` for the lowest rank, in at least
+///   [`Encoding::MIN_NUMBER_BITS`] bits,
+/// * one flag for each suit, lowest suit first,
+/// * one flag for each rank, lowest rank first.
+///
+/// This is the [Cactus Kev](https://suffe.cool/poker/evaluator.html) layout, stretched so that
+/// decks with more ranks and suits, such as [`Modern`](crate::decks::modern::Modern) with its
+/// jokers and [`Tarot`](crate::decks::tarot::Tarot) with its major arcana, get their own flags
+/// instead of overflowing into the next field. For a [`French`](crate::decks::french::French) deck,
+/// it is exactly a Cactus Kev number.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let encoding = Tarot::encoding();
+///
+/// assert_eq!(encoding.width(), 55);
+/// assert_eq!(
+///     encoding.guide(),
+///     "xxxxxxxx x🤡🧙😇👑🤴🧎💏 🏎💪🧑🍀⚖🙃💀🚭 😈🏢⭐🌙🌞🔔🌍K QJPT9876 5432AM🪄🏆 ⚔☆rrrrrr xxpppppp"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Encoding {
+    /// The names of the ranks in the order that they first show up in the deck, highest first.
+    ranks: Vec<&'static str>,
+    /// The names of the suits in the order that they first show up in the deck, highest first.
+    suits: Vec<&'static str>,
+    /// The number of bits needed for the largest prime.
+    prime_bits: u32,
+}
+
+impl Encoding {
+    pub const PRIME_BITS: u32 = 8;
+    pub const MIN_NUMBER_BITS: u32 = 4;
+
+    /// Creates the encoding for the ranks and suits of the cards in the passed in deck. Blank
+    /// cards are ignored.
+    #[must_use]
+    pub fn new<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    >(
+        deck: &Pile<RankType, SuitType>,
+    ) -> Self {
+        let mut encoding = Encoding::default();
+        let mut max_prime = 0;
+        for card in deck.v().iter().filter(|card| !card.is_blank()) {
+            let rank = card.rank.fluent_name_string();
+            if !encoding.ranks.contains(&rank) {
+                encoding.ranks.push(rank);
+            }
+            let suit = card.suit.fluent_name_string();
+            if !encoding.suits.contains(&suit) {
+                encoding.suits.push(suit);
+            }
+            max_prime = max_prime.max(card.rank.prime);
+        }
+        encoding.prime_bits = u32::BITS - max_prime.leading_zeros();
+        encoding
+    }
+
+    /// Returns `true` if the encoding fits into a `u64`.
+    #[must_use]
+    pub fn fits(&self) -> bool {
+        self.width() <= u64::BITS && self.prime_bits <= Self::PRIME_BITS
+    }
+
+    /// The number of bits that the encoding needs.
+    #[must_use]
+    pub fn width(&self) -> u32 {
+        self.rank_shift() + Self::count(self.ranks.len())
+    }
+
+    /// Returns the card's binary encoding, or `None` if the card's rank or suit isn't part of the
+    /// encoding, or if the encoding doesn't fit into a `u64`.
+    #[must_use]
+    pub fn encode<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        card: &Card<RankType, SuitType>,
+    ) -> Option<u64> {
+        if !self.fits() {
+            return None;
+        }
+        let rank = Self::number(&self.ranks, card.rank.fluent_name_string())?;
+        let suit = Self::number(&self.suits, card.suit.fluent_name_string())?;
+
+        Some(
+            1 << (self.rank_shift() + rank)
+                | 1 << (self.suit_shift() + suit)
+                | u64::from(rank) << Self::PRIME_BITS
+                | u64::from(card.rank.prime),
+        )
+    }
+
+    /// Returns the names of the rank and suit that the bits encode, or `None` if they aren't a
+    /// valid encoding of a card.
+    #[must_use]
+    pub fn decode(&self, bits: u64) -> Option<(&'static str, &'static str)> {
+        if !self.fits() {
+            return None;
+        }
+        let number = (bits >> Self::PRIME_BITS) & ((1 << self.number_bits()) - 1);
+        let suit_flags = (bits >> self.suit_shift()) & ((1 << self.suits.len()) - 1);
+        let rank_flags = bits >> self.rank_shift();
+        if suit_flags.count_ones() != 1 || rank_flags != 1 << number {
+            return None;
+        }
+        let rank = self
+            .ranks
+            .len()
+            .checked_sub(1 + usize::try_from(number).ok()?)?;
+        let suit = self.suits.len() - 1 - suit_flags.trailing_zeros() as usize;
+
+        Some((self.ranks[rank], self.suits[suit]))
+    }
+
+    /// Returns a guide to the bits of the encoding, with the index of each rank and the symbol
+    /// of each suit over its flag, `r` over the rank's number, `p` over the prime, and `x` over
+    /// the bits that aren't used, grouped into bytes like [`Bit::string64()`].
+    #[must_use]
+    pub fn guide(&self) -> String {
+        let rank_flags = self.ranks.iter().map(|name| {
+            FluentName::new(name)
+                .index_default()
+                .chars()
+                .next()
+                .unwrap_or('_')
+        });
+        let suit_flags = self.suits.iter().map(|name| {
+            FluentName::new(name)
+                .fluent_value(FluentName::FLUENT_SYMBOL_SECTION, &FluentName::US_ENGLISH)
+                .chars()
+                .next()
+                .unwrap_or('_')
+        });
+
+        let unused = u64::BITS.saturating_sub(self.width());
+        let chars = std::iter::repeat('x')
+            .take(unused as usize)
+            .chain(rank_flags)
+            .chain(suit_flags)
+            .chain(std::iter::repeat('r').take(self.number_bits() as usize))
+            .chain(std::iter::repeat('x').take((Self::PRIME_BITS - self.prime_bits) as usize))
+            .chain(std::iter::repeat('p').take(self.prime_bits as usize));
+
+        Bit::spaced(chars)
+    }
+
+    // Private methods
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn count(n: usize) -> u32 {
+        n as u32
+    }
+
+    /// How far the name is from the end of the list, so that the last one is `0`.
+    fn number(names: &[&'static str], name: &str) -> Option<u32> {
+        let position = names.iter().position(|n| *n == name)?;
+        Some(Self::count(names.len() - 1 - position))
+    }
+
+    fn number_bits(&self) -> u32 {
+        let highest = Self::count(self.ranks.len().saturating_sub(1));
+        (u32::BITS - highest.leading_zeros()).max(Self::MIN_NUMBER_BITS)
+    }
+
+    fn suit_shift(&self) -> u32 {
+        Self::PRIME_BITS + self.number_bits()
+    }
+
+    fn rank_shift(&self) -> u32 {
+        self.suit_shift() + Self::count(self.suits.len())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__encoding__tests {
+    use super::*;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::decks::skat::Skat;
+    use crate::decks::tarot::Tarot;
+    use crate::types::traits::{Decked, Encoded};
+
+    #[test]
+    fn encode__french_is_ckc() {
+        for card in French::deck() {
+            assert_eq!(French::encode(&card), card.get_ckc_number().map(u64::from));
+        }
+    }
+
+    #[test]
+    fn encode__modern_jokers() {
+        let encoding = Modern::encoding();
+
+        let big = encoding.encode(&Modern::big_joker()).unwrap();
+        let little = encoding.encode(&Modern::little_joker()).unwrap();
+
+        assert_eq!(big >> 31, 1);
+        assert_eq!(little >> 30, 1);
+        assert_eq!((big >> Encoding::PRIME_BITS) & 0b1111, 14);
+        assert_ne!(big & 1 << 16, 0);
+        assert_eq!(
+            encoding.decode(little),
+            Some((Modern::LITTLE, Modern::JOKER))
+        );
+    }
+
+    #[test]
+    fn encode__not_in_deck() {
+        assert_eq!(Skat::encoding().encode(&French::deck()[0]), None);
+        assert_eq!(Skat::encoding().encode(&French::blank()), None);
+    }
+
+    #[test]
+    fn decode() {
+        let deck = Tarot::deck();
+        let encoding = Encoding::new(&deck);
+
+        for card in &deck {
+            let bits = encoding.encode(card).unwrap();
+            assert_eq!(
+                encoding.decode(bits),
+                Some((
+                    card.rank.fluent_name_string(),
+                    card.suit.fluent_name_string()
+                ))
+            );
+        }
+        assert_eq!(French::encoding().decode(0), None);
+        assert_eq!(French::encoding().decode(u64::MAX), None);
+    }
+
+    #[test]
+    fn guide() {
+        let french = French::encoding().guide();
+        let skat = Skat::encoding().guide();
+
+        assert_eq!(&french[36..], "xxxAKQJT 98765432 ♠♥♦♣rrrr xxpppppp");
+        assert_eq!(&skat[36..], "xxxxxxxx DTKOU987 ♣♠♥♦rrrr xxxppppp");
+    }
+
+    #[test]
+    fn fits() {
+        assert!(Tarot::encoding().fits());
+        assert_eq!(Modern::encoding().width(), 32);
+        assert_eq!(Encoding::default().width(), 12);
+    }
+}
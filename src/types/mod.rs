@@ -1,8 +1,19 @@
 pub mod card;
 pub mod card_error;
+pub mod card_set;
 pub mod dealer;
+pub mod deck_builder;
+pub(crate) mod deck_table;
+pub mod diagnostic;
+pub mod encoding;
+pub mod notation;
+pub mod ordering;
 pub mod pile;
 pub mod rank;
+pub mod rank_profile;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod shuffle;
 pub mod suit;
 pub mod traits;
 pub mod utils;
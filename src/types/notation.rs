@@ -0,0 +1,417 @@
+//! Built in [`Notation`]s for parsing cards written down in other ways than their indexes, such as
+//! `10H`, `Ace of Spades` or `🂡`, so that hands pasted in from other tools just work.
+//!
+//! Each notation only knows about the names of ranks and suits, so they work for any deck that
+//! has those names. [`Flexible`] tries all of them, and is what `FromStr` uses for
+//! [`Card`] and [`Pile`](crate::types::pile::Pile).
+use crate::decks::french::French;
+use crate::decks::modern::Modern;
+use crate::decks::tarot::Tarot;
+use crate::localization::{FluentName, Named};
+use crate::types::card::Card;
+use crate::types::rank::Rank;
+use crate::types::suit::Suit;
+use crate::types::traits::{Notation, Ranked, Suited};
+use fluent_templates::LanguageIdentifier;
+
+/// A rank char followed by a suit char, such as `AS`, `as` or `A♠`. It also takes a
+/// separator between them, as in `A-S`, `10` for a ten, and ranks written with more than one
+/// char, such as the `🧑‍🌾` of the Tarot's hermit.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::notation::Index;
+///
+/// assert_eq!(FrenchCard::from_str_with("10H", &Index).unwrap(), card!(TH));
+/// assert_eq!(FrenchCard::from_str_with("a-s", &Index).unwrap(), card!(AS));
+/// assert_eq!(
+///     Card::<Tarot, Tarot>::from_str_with("🧑‍🌾M", &Index).unwrap(),
+///     Tarot::from_ordinal(9).unwrap()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Index;
+
+impl Index {
+    pub(crate) const SEPARATORS: [char; 6] = ['-', '_', '/', '.', ':', ' '];
+
+    /// The rank of an index with its suit char taken off, such as the `10-` of `10-H`, or
+    /// `None` if it isn't one of the deck's.
+    pub(crate) fn rank<RankType: Ranked>(rank: &str) -> Option<Rank<RankType>> {
+        match rank.trim_end_matches(Index::SEPARATORS) {
+            "10" => Rank::from_char('T').ok(),
+            rank if rank.chars().count() == 1 => Rank::from_char(rank.chars().next()?).ok(),
+            rank => Some(Rank::new(rank_named::<RankType>(|name| {
+                name.index_default().eq_ignore_ascii_case(rank)
+            })?)),
+        }
+    }
+
+    /// The suit for the last char of an index, or `None` if it isn't one of the deck's.
+    pub(crate) fn suit<SuitType: Suited>(suit: char) -> Option<Suit<SuitType>> {
+        let suit = Suit::<SuitType>::from(suit);
+        (!suit.is_blank()).then_some(suit)
+    }
+}
+
+impl Notation for Index {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        let mut chars = s.chars();
+        let suit = chars.next_back()?;
+        let rank = chars.as_str();
+
+        // Two chars is the plain index, which is parsed the same way that it always has been.
+        if rank.chars().count() == 1 {
+            let rank = rank.chars().next()?;
+            return Some(Card::new(Rank::from(rank), Suit::from(suit)));
+        }
+
+        Some(Card::new(Index::rank(rank)?, Index::suit(suit)?))
+    }
+}
+
+/// A card written out in English, such as `Ace of Spades`, ignoring case. The ranks of a deck's
+/// special suit are written on their own, such as `The Fool`, or in front of the suit, such
+/// as `Big Joker`. Names are matched against the long names in the fluent templates, as well as
+/// the fluent names themselves, with spaces for hyphens.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::notation::LongName;
+///
+/// assert_eq!(FrenchCard::from_str_with("Ace of Spades", &LongName).unwrap(), card!(AS));
+/// assert_eq!(ModernCard::from_str_with("big joker", &LongName).unwrap(), Modern::big_joker());
+/// assert_eq!(
+///     Card::<Tarot, Tarot>::from_str_with("The Fool", &LongName).unwrap(),
+///     card!(Tarot: "🤡M")
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LongName;
+
+impl LongName {
+    fn is_named(name: &str, s: &str) -> bool {
+        let fluent_name = FluentName::new(name);
+        fluent_name.long_default().eq_ignore_ascii_case(s)
+            || name.replace('-', " ").eq_ignore_ascii_case(s)
+    }
+}
+
+impl Notation for LongName {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        let lower = s.to_ascii_lowercase();
+
+        if let Some(at) = lower.find(" of ") {
+            let (rank, suit) = (&s[..at], &s[at + 4..]);
+            let rank =
+                rank_named::<RankType>(|name| LongName::is_named(name.fluent_name_string(), rank))?;
+            let suit = suit_named::<SuitType>(|name| LongName::is_named(name, suit))?;
+            return Some(Card::new(Rank::new(rank), Suit::new(suit)));
+        }
+
+        // The ranks of a special suit, on their own or in front of the suit.
+        for suit in SuitType::special_suit_names() {
+            let long = FluentName::new(suit).long_default();
+            let prefix = strip_suffix_ignore_case(&s, &long)
+                .or_else(|| strip_suffix_ignore_case(&s, &suit.replace('-', " ")))
+                .map(str::trim_end);
+            for rank in RankType::special_rank_names() {
+                if LongName::is_named(rank, &s)
+                    || prefix.is_some_and(|prefix| LongName::is_named(rank, prefix))
+                {
+                    return Some(Card::new(Rank::new(rank), Suit::new(suit)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The cards in the Unicode [Playing Cards](https://en.wikipedia.org/wiki/Playing_cards_in_Unicode)
+/// block, such as `🂡` for the ace of spades. The minor arcana of a tarot deck are read from
+/// the French suits, with swords for spades, cups for hearts, pentacles for diamonds and wands
+/// for clubs, and its trumps are the major arcana. The red joker is the big joker, and the
+/// black and white jokers are the little one.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::notation::Glyph;
+///
+/// assert_eq!(FrenchCard::from_str_with("🂮", &Glyph).unwrap(), card!(KS));
+/// assert_eq!(ModernCard::from_str_with("🂿", &Glyph).unwrap(), Modern::big_joker());
+/// assert_eq!(Card::<Tarot, Tarot>::from_str_with("🃠", &Glyph).unwrap(), card!(Tarot: "🤡M"));
+/// assert_eq!(Card::<Tarot, Tarot>::from_str_with("🂡", &Glyph).unwrap(), card!(Tarot: "A⚔"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Glyph;
+
+impl Glyph {
+    const FIRST: u32 = 0x1F0A0;
+    const TRUMPS: u32 = 0x1F0E0;
+    const LAST: u32 = 0x1F0F5;
+
+    /// The suits of each row of the block, along with their tarot equivalents.
+    const SUITS: [[&'static str; 2]; 4] = [
+        [French::SPADES, Tarot::SWORDS],
+        [French::HEARTS, Tarot::CUPS],
+        [French::DIAMONDS, Tarot::PENTACLES],
+        [French::CLUBS, Tarot::WANDS],
+    ];
+
+    /// The ranks of each column of the block, along with their tarot equivalents.
+    fn ranks(column: u32) -> &'static [&'static str] {
+        match column {
+            0x1 => &[French::ACE],
+            0x2 => &[French::TWO],
+            0x3 => &[French::THREE],
+            0x4 => &[French::FOUR],
+            0x5 => &[French::FIVE],
+            0x6 => &[French::SIX],
+            0x7 => &[French::SEVEN],
+            0x8 => &[French::EIGHT],
+            0x9 => &[French::NINE],
+            0xA => &[French::TEN],
+            0xB => &[French::JACK, Tarot::PAGE],
+            0xC => &[Tarot::KNIGHT],
+            0xD => &[French::QUEEN],
+            0xE => &[French::KING],
+            _ => &[],
+        }
+    }
+}
+
+impl Notation for Glyph {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        let mut chars = s.chars();
+        let code = u32::from(chars.next()?);
+        if chars.next().is_some() || !(Glyph::FIRST..=Glyph::LAST).contains(&code) {
+            return None;
+        }
+
+        let trump;
+        let (ranks, suits): (&[&str], &[&str]) = match code {
+            0x1F0BF => (&[Modern::BIG], &[Modern::JOKER]),
+            0x1F0CF | 0x1F0DF => (&[Modern::LITTLE], &[Modern::JOKER]),
+            Glyph::TRUMPS.. => {
+                trump = Tarot::special_rank_names()
+                    .get((code - Glyph::TRUMPS) as usize)
+                    .copied()?;
+                (std::slice::from_ref(&trump), &[Tarot::MAJOR_ARCANA])
+            }
+            _ => (
+                Glyph::ranks(code & 0xF),
+                Glyph::SUITS.get(((code - Glyph::FIRST) >> 4) as usize)?,
+            ),
+        };
+        let rank = rank_named::<RankType>(|name| ranks.contains(&name.fluent_name_string()))?;
+        let suit = suit_named::<SuitType>(|name| suits.contains(&name))?;
+        Some(Card::new(Rank::new(rank), Suit::new(suit)))
+    }
+}
+
+/// An index written with the letters of a language other than English, such as `BH` for the
+/// German Bube (jack) of hearts and `DK` for the Dame (queen) of Kreuz (clubs). The letters come
+/// from the `-index` entries of the fluent templates for the language, falling back to the
+/// English ones where it doesn't have any. As with [`Index`], case is ignored and a separator
+/// between the rank and the suit is fine.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::notation::Localized;
+///
+/// let german = Localized(FluentName::DEUTSCH);
+///
+/// assert_eq!(FrenchCard::from_str_with("BH", &german).unwrap(), card!(JH));
+/// assert_eq!(FrenchCard::from_str_with("d-k", &german).unwrap(), card!(QC));
+/// assert!(FrenchCard::from_str_with("QC", &german).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Localized(pub LanguageIdentifier);
+
+impl Notation for Localized {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        SuitType::suit_names()
+            .into_iter()
+            .chain(SuitType::special_suit_names())
+            .find_map(|suit| {
+                let rank = strip_suffix_ignore_case(s, &FluentName::new(suit).index(&self.0))?
+                    .trim_end_matches(Index::SEPARATORS);
+                let rank = rank_named::<RankType>(|name| {
+                    !rank.is_empty() && name.index(&self.0).eq_ignore_ascii_case(rank)
+                })?;
+                Some(Card::new(Rank::new(rank), Suit::new(suit)))
+            })
+    }
+}
+
+/// Every built in notation: [`Index`], then [`LongName`], then [`Glyph`]. This is the notation
+/// that `FromStr` uses for [`Card`] and [`Pile`](crate::types::pile::Pile).
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// assert_eq!(
+///     French::from_str("[As, 10h, Queen of Diamonds, 🃑]").unwrap().to_string(),
+///     "A♠ T♥ Q♦ A♣"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Flexible;
+
+impl Notation for Flexible {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        (Index, LongName, Glyph).parse(s)
+    }
+}
+
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let at = s.len().checked_sub(suffix.len())?;
+    (s.is_char_boundary(at) && s[at..].eq_ignore_ascii_case(suffix)).then(|| &s[..at])
+}
+
+/// The first of the deck's ranks, including the special ones, that matches.
+fn rank_named<RankType: Ranked>(f: impl Fn(&FluentName) -> bool) -> Option<&'static str> {
+    RankType::rank_names()
+        .into_iter()
+        .chain(RankType::special_rank_names())
+        .find(|name| f(&FluentName::new(name)))
+}
+
+/// The first of the deck's suits, including the special ones, that matches.
+fn suit_named<SuitType: Suited>(f: impl Fn(&'static str) -> bool) -> Option<&'static str> {
+    SuitType::suit_names()
+        .into_iter()
+        .chain(SuitType::special_suit_names())
+        .find(|name| f(name))
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__notation__tests {
+    use super::*;
+    use crate::decks::skat::Skat;
+    use crate::types::pile::Pile;
+    use crate::types::traits::Decked;
+    use std::str::FromStr;
+
+    #[test]
+    fn index() {
+        let ten = Card::<French, French>::from_str("TH").unwrap();
+
+        assert_eq!(Index.parse::<French, French>("10H"), Some(ten));
+        assert_eq!(Index.parse::<French, French>("10-h"), Some(ten));
+        assert_eq!(Index.parse::<Skat, Skat>("10E").unwrap().to_string(), "T♣");
+        assert_eq!(Index.parse::<French, French>("11H"), None);
+        assert_eq!(Index.parse::<French, French>("A-X"), None);
+        assert_eq!(Index.parse::<French, French>(""), None);
+    }
+
+    #[test]
+    fn long_name() {
+        assert_eq!(
+            LongName.parse::<French, French>("  king   OF hearts "),
+            Some(Card::from_str("KH").unwrap())
+        );
+        assert_eq!(
+            LongName.parse::<Modern, Modern>("One-Color Joker"),
+            Some(Modern::little_joker())
+        );
+        assert_eq!(LongName.parse::<French, French>("Big Joker"), None);
+        assert_eq!(LongName.parse::<French, French>("Ace of Cups"), None);
+    }
+
+    #[test]
+    fn glyph() {
+        assert_eq!(Glyph.parse::<Tarot, Tarot>("🃵").unwrap().to_string(), "🌍M");
+        assert_eq!(Glyph.parse::<Tarot, Tarot>("🃜").unwrap().to_string(), "J🪄");
+        assert_eq!(Glyph.parse::<French, French>("🂬"), None);
+        assert_eq!(Glyph.parse::<French, French>("🂠"), None);
+        assert_eq!(Glyph.parse::<French, French>("🂡🂡"), None);
+    }
+
+    #[test]
+    fn glyph__trumps_only_in_the_tarot() {
+        assert_eq!(Glyph.parse::<French, French>("🃠"), None);
+        assert_eq!(Glyph.parse::<Modern, Modern>("🃠"), None);
+        assert_eq!(Glyph.parse::<Modern, Modern>("🃵"), None);
+        assert_eq!(
+            Pile::<French, French>::from_str("🃠 AS")
+                .unwrap_err()
+                .to_string(),
+            "Invalid Pile: `🃠` at 0..4: not a card"
+        );
+        assert_eq!(
+            Pile::<Modern, Modern>::from_str("🂿 🃠")
+                .unwrap_err()
+                .to_string(),
+            "Invalid Pile: `🃠` at 5..9: not a card"
+        );
+    }
+
+    #[test]
+    fn localized() {
+        let german = Localized(FluentName::DEUTSCH);
+
+        assert_eq!(
+            german.parse::<French, French>("bh").unwrap().to_string(),
+            "J♥"
+        );
+        assert_eq!(
+            german.parse::<French, French>("KK").unwrap().to_string(),
+            "K♣"
+        );
+        assert_eq!(german.parse::<French, French>("JH"), None);
+        assert_eq!(german.parse::<French, French>("H"), None);
+    }
+
+    #[test]
+    fn localized__round_trip() {
+        let german = Localized(FluentName::DEUTSCH);
+
+        for card in &French::deck() {
+            let index = card.index_localized(&FluentName::DEUTSCH);
+            assert_eq!(german.parse::<French, French>(&index).as_ref(), Some(card));
+        }
+        for card in &Skat::deck() {
+            let index = card.index_localized(&FluentName::DEUTSCH);
+            assert_eq!(german.parse::<Skat, Skat>(&index).as_ref(), Some(card));
+        }
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(Flexible.split("[As, Kd]"), vec!["As", "Kd"]);
+        assert_eq!(Flexible.split("\"AS\";'KD';"), vec!["AS", "KD"]);
+        assert_eq!(Flexible.split(" (AS  KD) "), vec!["AS", "KD"]);
+        assert!(Flexible.split("[]").is_empty());
+    }
+
+    #[test]
+    fn pile__from_str() {
+        let hand = Pile::<French, French>::from_str("Ace of Spades, 10♥, k-d, 🃑").unwrap();
+
+        assert_eq!(hand.to_string(), "A♠ T♥ K♦ A♣");
+        assert_eq!(
+            Pile::<French, French>::from_str("AS, Ace of Cups")
+                .unwrap_err()
+                .to_string(),
+            "Invalid Pile: `Ace of Cups` at 4..15: not a card"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Built in [`CardOrdering`] strategies for [`Pile::sort_by_ordering()`](crate::types::pile::Pile::sort_by_ordering).
+//!
+//! Every one of them puts the highest cards first, the way that a hand of cards is usually held,
+//! and they all fall back on the natural order of the cards to break ties, so that sorting is
+//! always deterministic.
+use crate::types::card::Card;
+use crate::types::suit::Suit;
+use crate::types::traits::{CardOrdering, Ranked, Suited};
+use std::cmp::Ordering;
+
+/// Sorts by the cards' `weight` field, highest first. This is how
+/// [`Pile::sort()`](crate::types::pile::Pile::sort) sorts, which lets decks like
+/// [`Canasta`](crate::decks::canasta::Canasta) reweight cards so that they sort differently.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::ordering::ByWeight;
+///
+/// let pile = French::from_str("2C AS KH").unwrap();
+///
+/// assert_eq!(pile.sort_by_ordering(&ByWeight).to_string(), "A♠ K♥ 2♣");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ByWeight;
+
+impl<RankType: Ranked + Clone + Ord, SuitType: Suited + Clone + Ord>
+    CardOrdering<RankType, SuitType> for ByWeight
+{
+    fn compare(&self, a: &Card<RankType, SuitType>, b: &Card<RankType, SuitType>) -> Ordering {
+        b.weight.cmp(&a.weight).then_with(|| b.cmp(a))
+    }
+}
+
+/// Sorts by rank, highest first, regardless of suit, which is how most people hold a poker hand.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::ordering::ByRank;
+///
+/// let pile = French::from_str("KS AD KH 2S").unwrap();
+///
+/// assert_eq!(pile.sort_by_ordering(&ByRank).to_string(), "A♦ K♠ K♥ 2♠");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ByRank;
+
+impl<RankType: Ranked + Clone + Ord, SuitType: Suited + Clone + Ord>
+    CardOrdering<RankType, SuitType> for ByRank
+{
+    fn compare(&self, a: &Card<RankType, SuitType>, b: &Card<RankType, SuitType>) -> Ordering {
+        b.rank.cmp(&a.rank).then_with(|| b.suit.cmp(&a.suit))
+    }
+}
+
+/// Sorts the cards of the trump suit first, and then the rest of the cards by their weight.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::ordering::TrumpFirst;
+///
+/// let pile = French::from_str("AS 2C KH 9C").unwrap();
+/// let clubs = TrumpFirst::new(Suit::<French>::from('C'));
+///
+/// assert_eq!(pile.sort_by_ordering(&clubs).to_string(), "9♣ 2♣ A♠ K♥");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TrumpFirst<SuitType: Suited> {
+    pub trump: Suit<SuitType>,
+}
+
+impl<SuitType: Suited> TrumpFirst<SuitType> {
+    #[must_use]
+    pub fn new(trump: Suit<SuitType>) -> Self {
+        Self { trump }
+    }
+}
+
+impl<RankType: Ranked + Clone + Ord, SuitType: Suited + Clone + Ord>
+    CardOrdering<RankType, SuitType> for TrumpFirst<SuitType>
+{
+    fn compare(&self, a: &Card<RankType, SuitType>, b: &Card<RankType, SuitType>) -> Ordering {
+        let a_trump = a.suit == self.trump;
+        let b_trump = b.suit == self.trump;
+        b_trump.cmp(&a_trump).then_with(|| ByWeight.compare(a, b))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__ordering__tests {
+    use super::*;
+    use crate::decks::canasta::Canasta;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::types::pile::Pile;
+    use crate::types::traits::Decked;
+    use std::str::FromStr;
+
+    #[test]
+    fn by_weight() {
+        let deck = French::deck();
+
+        assert_eq!(deck.shuffle().sort_by_ordering(&ByWeight), deck);
+    }
+
+    #[test]
+    fn by_weight__reweighted() {
+        let pile = Pile::<Modern, Modern>::from(vec![
+            Card::from_str("AS").unwrap(),
+            Canasta::two_of_clubs(),
+        ]);
+
+        assert_eq!(pile.sort_by_ordering(&ByWeight).to_string(), "2♣ A♠");
+    }
+
+    #[test]
+    fn by_rank() {
+        let mut pile = French::deck().sort_by_ordering(&ByRank);
+
+        assert_eq!(pile.draw(5).to_string(), "A♠ A♥ A♦ A♣ K♠");
+    }
+
+    #[test]
+    fn trump_first() {
+        let trumps = TrumpFirst::new(Suit::<French>::from('D'));
+        let pile = French::deck().sort_by_ordering(&trumps);
+
+        assert_eq!(pile.get(0).unwrap().to_string(), "A♦");
+        assert_eq!(pile.get(12).unwrap().to_string(), "2♦");
+        assert_eq!(pile.get(13).unwrap().to_string(), "A♠");
+    }
+}
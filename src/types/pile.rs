@@ -1,14 +1,26 @@
+use crate::localization::{self, FluentName, Named};
 use crate::types::card::Card;
 use crate::types::card_error::CardError;
+use crate::types::diagnostic::Diagnostics;
+use crate::types::notation::{Flexible, Localized};
+use crate::types::ordering::ByWeight;
 use crate::types::rank::Rank;
+use crate::types::rank_profile::RankProfile;
 use crate::types::suit::Suit;
+use crate::types::traits::CardOrdering;
+use crate::types::traits::Notation;
 use crate::types::traits::Ranked;
+use crate::types::traits::Shufflable;
 use crate::types::traits::Suited;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
+use crate::types::utils::Random;
+use fluent_templates::LanguageIdentifier;
+use hashbag::HashBag;
+use rand::{thread_rng, Rng};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+use std::slice::SliceIndex;
 use std::str::FromStr;
 
 /// `Pile` is a [generic data type](https://doc.rust-lang.org/book/ch10-01-syntax.html)
@@ -114,6 +126,75 @@ impl<
         self.0.contains(card)
     }
 
+    /// Returns a [`HashBag`] of the cards in the `Pile`, which, unlike
+    /// [`as_hashset()`](Pile::as_hashset), keeps track of how many copies of each card
+    /// there are.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = HandAndFoot::deck();
+    /// let five_of_hearts = ModernCard::from_str("5H").unwrap();
+    ///
+    /// let counts = pile.counts();
+    ///
+    /// assert_eq!(counts.len(), 270);
+    /// assert_eq!(counts.set_len(), 54);
+    /// assert_eq!(counts.contains(&five_of_hearts), 5);
+    /// ```
+    #[must_use]
+    pub fn counts(&self) -> HashBag<Card<RankType, SuitType>> {
+        self.0.iter().cloned().collect()
+    }
+
+    /// Returns the cards in the `Pile` that aren't in the passed in `Pile`, with each card
+    /// in the other `Pile` only taking away a single copy. The cards stay in their original
+    /// order.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = PinochleDeck::from_str("AS AS QS QS JD JD").unwrap();
+    /// let meld = PinochleDeck::from_str("QS JD").unwrap();
+    ///
+    /// assert_eq!(pile.difference(&meld).to_string(), "A♠ A♠ Q♠ J♦");
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &Pile<RankType, SuitType>) -> Self {
+        let mut others = other.counts();
+        Pile(
+            self.0
+                .iter()
+                .filter(|card| others.remove(*card) == 0)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Cuts the `Pile`, moving the top `at` cards to the bottom.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut pile = French::from_str("AS KS QS JS").unwrap();
+    ///
+    /// pile.cut(1).unwrap();
+    ///
+    /// assert_eq!(pile.to_string(), "K♠ Q♠ J♠ A♠");
+    /// assert_eq!(pile.cut(5), Err(CardError::NotEnoughCards(1)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotEnoughCards` if there are fewer than `at` cards in the `Pile`.
+    pub fn cut(&mut self, at: usize) -> Result<(), CardError> {
+        if at > self.len() {
+            return Err(CardError::NotEnoughCards(at - self.len()));
+        }
+        self.0.rotate_left(at);
+        Ok(())
+    }
+
+    /// Draws up to `n` cards from the top of the `Pile`. Use [`Pile::try_draw()`] if there need to
+    /// be exactly `n`.
+    ///
     /// ```
     /// use cardpack::prelude::*;
     /// let mut pile = French::deck();
@@ -141,10 +222,7 @@ impl<
     /// assert_eq!(card.to_string(), "2♣");
     /// ```
     pub fn draw_first(&mut self) -> Option<Card<RankType, SuitType>> {
-        match self.len() {
-            0 => None,
-            _ => Some(self.remove(0)),
-        }
+        self.draw_at(0)
     }
 
     /// ```
@@ -158,16 +236,90 @@ impl<
         self.0.pop()
     }
 
+    /// Draws the card at the zero indexed position, or returns `None` if there isn't one.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut pile = French::from_str("AS KS QS").unwrap();
+    ///
+    /// assert_eq!(pile.draw_at(1), Some(card!(KS)));
+    /// assert_eq!(pile.draw_at(2), None);
+    /// assert_eq!(pile.to_string(), "A♠ Q♠");
+    /// ```
+    pub fn draw_at(&mut self, position: usize) -> Option<Card<RankType, SuitType>> {
+        if position < self.len() {
+            Some(self.0.remove(position))
+        } else {
+            None
+        }
+    }
+
+    /// Draws `n` cards from random places in the `Pile`, such as when picking cards out of a
+    /// fanned deck.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotEnoughCards` if there are fewer than `n` cards in the `Pile`.
+    pub fn draw_random(&mut self, n: usize) -> Result<Self, CardError> {
+        self.draw_random_with_rng(n, &mut thread_rng())
+    }
+
+    /// Draws `n` cards from random places in the `Pile`, using the passed in RNG. The cards left
+    /// behind stay in the same order, and the `Pile` is left alone if there aren't enough cards.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::types::utils::Random;
+    /// let mut pile = French::deck();
+    ///
+    /// let drawn = pile.draw_random_with_rng(5, &mut Random::seeded(3)).unwrap();
+    ///
+    /// assert_eq!(drawn.len(), 5);
+    /// assert_eq!(pile.len(), 47);
+    /// assert!(drawn.is_subset(&French::deck()));
+    /// assert!(pile.draw_random_with_rng(48, &mut Random::seeded(3)).is_err());
+    /// assert_eq!(pile.len(), 47);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotEnoughCards` if there are fewer than `n` cards in the `Pile`.
+    pub fn draw_random_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        n: usize,
+        rng: &mut R,
+    ) -> Result<Self, CardError> {
+        if n > self.len() {
+            return Err(CardError::NotEnoughCards(n - self.len()));
+        }
+        // A partial Fisher–Yates shuffle of the positions, so that each card is only moved once.
+        let mut positions: Vec<usize> = (0..self.len()).collect();
+        let mut drawn = vec![false; self.len()];
+        let mut pile = Pile::<RankType, SuitType>::default();
+        for i in 0..n {
+            positions.swap(i, i + Random::below(rng, self.len() - i));
+            drawn[positions[i]] = true;
+            pile.0.push(self.0[positions[i]].clone());
+        }
+        let mut drawn = drawn.into_iter();
+        self.0.retain(|_| !drawn.next().unwrap_or_default());
+        Ok(pile)
+    }
+
+    /// Removes the card at the zero indexed position, the same as [`draw_at()`](Pile::draw_at),
+    /// returning `None` instead of panicking if there isn't one.
+    ///
     /// ```
     /// use cardpack::prelude::*;
     /// let mut pile = French::deck();
-    /// let card = pile.remove(1);
+    /// let card = pile.remove(1).unwrap();
     ///
     /// assert_eq!(card.to_string(), "K♠");
     /// assert_eq!(pile.draw(2).to_string(), "A♠ Q♠");
+    /// assert_eq!(pile.remove(50), None);
     /// ```
-    pub fn remove(&mut self, index: usize) -> Card<RankType, SuitType> {
-        self.0.remove(index)
+    pub fn remove(&mut self, index: usize) -> Option<Card<RankType, SuitType>> {
+        self.draw_at(index)
     }
 
     /// Extends the `Pile` with the contents of the passed in `Pile`.
@@ -185,6 +337,81 @@ impl<
         self.0.extend(other.0.clone());
     }
 
+    /// Parses a list of cards with the passed in [`Notation`], which also decides how the list is
+    /// [split up](Notation::split).
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::types::notation::Index;
+    ///
+    /// let hand = FrenchDeck::from_str_with("[10s, j-s, QS]", &Index).unwrap();
+    ///
+    /// assert_eq!(hand.to_string(), "T♠ J♠ Q♠");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidIndex` with the first card that isn't valid, or with the whole
+    /// index if there aren't any cards in it.
+    pub fn from_str_with<N: Notation>(index: &str, notation: &N) -> Result<Self, CardError> {
+        let mut cards = Pile::<RankType, SuitType>::default();
+        for s in notation.split(index) {
+            if !cards.push(Card::<RankType, SuitType>::from_str_with(s, notation)?) {
+                return Err(CardError::InvalidIndex(s.to_string()));
+            }
+        }
+
+        if cards.is_empty() {
+            Err(CardError::InvalidIndex(index.to_string()))
+        } else {
+            Ok(cards)
+        }
+    }
+
+    /// Parses an index written with the letters of the passed in language, such as `BH DK` for
+    /// the jack of hearts and queen of clubs in German. Anything that isn't a
+    /// [`Localized`] index falls back to the [`Flexible`] notation that `FromStr` uses, so
+    /// `10H` and `Ace of Spades` still work.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let pile = Pile::<French, French>::from_str_localized("BH DK 10S", &FluentName::DEUTSCH);
+    ///
+    /// assert_eq!(pile.unwrap().to_string(), "J♥ Q♣ T♠");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidIndex` with the first card that isn't valid, or with the whole
+    /// index if it doesn't have any cards.
+    pub fn from_str_localized(index: &str, lid: &LanguageIdentifier) -> Result<Self, CardError> {
+        Pile::from_str_with(index, &(Localized(lid.clone()), Flexible))
+    }
+
+    /// Parses the index the same way that `FromStr` does, but instead of stopping at the first
+    /// bad card, it returns a [`Diagnostic`](crate::types::diagnostic::Diagnostic) for every
+    /// one of them, with where it is, why it failed, and the cards that might have been meant.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let diagnostics = Pile::<French, French>::diagnose("AS KX").unwrap_err();
+    /// let diagnostic = &diagnostics.v()[0];
+    ///
+    /// assert_eq!(diagnostic.position, 1);
+    /// assert_eq!(diagnostic.span, 3..5);
+    /// assert_eq!(diagnostic.reason, ParseReason::UnknownSuit("X".to_string()));
+    /// assert_eq!(diagnostic.suggestions, vec!["KS", "KH", "KD", "KC"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Diagnostics`] with every card that couldn't be parsed.
+    pub fn diagnose(index: &str) -> Result<Self, Diagnostics> {
+        Diagnostics::parse(index, None)
+    }
+
     /// Returns a Card at the specific passed in position.
     ///
     /// ```
@@ -212,10 +439,154 @@ impl<
     pub fn index(&self) -> String {
         self.iter()
             .map(|c| c.index)
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// The same as [`index()`](Pile::index), but with the letters of the passed in language,
+    /// which [`from_str_localized()`](Pile::from_str_localized) parses back.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = French::from_str("JH QC TS").unwrap();
+    ///
+    /// assert_eq!(pile.index_localized(&FluentName::DEUTSCH), "BH DK TS");
+    /// assert_eq!(
+    ///     Pile::<French, French>::from_str_localized("BH DK TS", &FluentName::DEUTSCH).unwrap(),
+    ///     pile
+    /// );
+    /// ```
+    #[must_use]
+    pub fn index_localized(&self, lid: &LanguageIdentifier) -> String {
+        self.iter()
+            .map(|c| c.index_localized(lid))
             .collect::<Vec<String>>()
             .join(" ")
     }
 
+    /// The cards written out in the passed in language as a list, with each run of cards in the
+    /// same suit sharing it. Cards in a special suit, such as jokers, are written out on their
+    /// own with [`Card::long()`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = French::from_str("AH KH QH 2C").unwrap();
+    ///
+    /// assert_eq!(
+    ///     pile.long(&FluentName::US_ENGLISH),
+    ///     "Ace, King and Queen of Hearts and Deuce of Clubs"
+    /// );
+    /// assert_eq!(
+    ///     French::from_str("AH KH QH").unwrap().long(&FluentName::DEUTSCH),
+    ///     "Ass, König und Dame von Herzen"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn long(&self, lid: &LanguageIdentifier) -> String {
+        let mut runs: Vec<(Option<FluentName>, Vec<String>)> = Vec::new();
+        for card in self {
+            if card.suit.is_special() {
+                runs.push((None, vec![card.long(lid)]));
+                continue;
+            }
+            match runs.last_mut() {
+                Some((Some(suit), ranks)) if *suit == card.suit.name => {
+                    ranks.push(card.rank.name.long(lid));
+                }
+                _ => runs.push((Some(card.suit.name), vec![card.rank.name.long(lid)])),
+            }
+        }
+
+        let runs: Vec<String> = runs
+            .into_iter()
+            .map(|(suit, ranks)| match suit {
+                Some(suit) => localization::message(
+                    lid,
+                    "pile-suit",
+                    &[
+                        ("ranks", localization::list(lid, &ranks)),
+                        ("suit", suit.long(lid)),
+                    ],
+                ),
+                None => ranks.concat(),
+            })
+            .collect();
+        localization::list(lid, &runs)
+    }
+
+    /// Inserts the card at the zero indexed position, moving the cards after it down.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut pile = French::from_str("AS QS").unwrap();
+    ///
+    /// pile.insert_at(1, card!(KS)).unwrap();
+    ///
+    /// assert_eq!(pile.to_string(), "A♠ K♠ Q♠");
+    /// assert_eq!(pile.insert_at(5, card!(JS)), Err(CardError::InvalidPosition(5)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidPosition` if the position is past the bottom of the `Pile`.
+    pub fn insert_at(
+        &mut self,
+        position: usize,
+        card: Card<RankType, SuitType>,
+    ) -> Result<(), CardError> {
+        if position > self.len() {
+            return Err(CardError::InvalidPosition(position));
+        }
+        self.0.insert(position, card);
+        Ok(())
+    }
+
+    /// Alternates the cards of the two `Piles`, starting with this one. Once one of them runs
+    /// out, the rest of the other one goes on the bottom.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let spades = French::from_str("AS KS QS").unwrap();
+    /// let hearts = French::from_str("AH KH").unwrap();
+    ///
+    /// assert_eq!(spades.interleave(&hearts).to_string(), "A♠ A♥ K♠ K♥ Q♠");
+    /// ```
+    #[must_use]
+    pub fn interleave(&self, other: &Pile<RankType, SuitType>) -> Self {
+        let mut pile = Pile::<RankType, SuitType>::default();
+        let mut mine = self.0.iter();
+        let mut theirs = other.0.iter();
+        loop {
+            match (mine.next(), theirs.next()) {
+                (None, None) => break,
+                (a, b) => pile.0.extend(a.into_iter().chain(b).cloned()),
+            }
+        }
+        pile
+    }
+
+    /// Returns the cards that are in both `Piles`, keeping as many copies of each card as the
+    /// `Pile` with the fewest of them. The cards stay in their original order.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = PinochleDeck::from_str("AS AS QS QS JD JD").unwrap();
+    /// let other = PinochleDeck::from_str("JD QS QS QS").unwrap();
+    ///
+    /// assert_eq!(pile.intersection(&other).to_string(), "Q♠ Q♠ J♦");
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Pile<RankType, SuitType>) -> Self {
+        let mut others = other.counts();
+        Pile(
+            self.0
+                .iter()
+                .filter(|card| others.remove(*card) > 0)
+                .cloned()
+                .collect(),
+        )
+    }
+
     /// ```
     /// use cardpack::prelude::*;
     /// assert!(FrenchDeck::default().is_empty());
@@ -230,6 +601,26 @@ impl<
         self.0.is_empty()
     }
 
+    /// Returns true if every card in the `Pile` is in the passed in `Pile`, with at least as
+    /// many copies.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let deck = HandAndFoot::deck();
+    /// let three_fives = ModernDeck::from_str("5H 5H 5H").unwrap();
+    /// let six_fives = ModernDeck::from_str("5H 5H 5H 5H 5H 5H").unwrap();
+    ///
+    /// assert!(three_fives.is_subset(&deck));
+    /// assert!(!six_fives.is_subset(&deck));
+    /// ```
+    #[must_use]
+    pub fn is_subset(&self, other: &Pile<RankType, SuitType>) -> bool {
+        let others = other.counts();
+        self.counts()
+            .set_iter()
+            .all(|(card, count)| others.contains(card) >= count)
+    }
+
     /// Returns the length of the `Pile`.
     ///
     /// ```
@@ -242,17 +633,32 @@ impl<
         self.0.len()
     }
 
+    /// Borrows the cards in the `Pile`, from the top down, without cloning them.
+    ///
     /// ```
     /// use cardpack::prelude::*;
     /// let pile = French::deck();
     ///
     /// for card in pile.iter() {
-    ///   assert!(pile.contains(&card));
+    ///   assert!(pile.contains(card));
     /// }
     /// ```
-    #[must_use]
-    pub fn iter(&self) -> std::vec::IntoIter<<Pile<RankType, SuitType> as IntoIterator>::Item> {
-        <&Self as IntoIterator>::into_iter(self)
+    pub fn iter(&self) -> std::slice::Iter<'_, Card<RankType, SuitType>> {
+        self.0.iter()
+    }
+
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut pile = French::from_str("AS KS").unwrap();
+    ///
+    /// for card in pile.iter_mut() {
+    ///   card.weight = 0;
+    /// }
+    ///
+    /// assert!(pile.iter().all(|card| card.weight == 0));
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Card<RankType, SuitType>> {
+        self.0.iter_mut()
     }
 
     /// Takes the `Pile` and returns a `HashMap` of the cards mapped by their [`Suit`].
@@ -336,6 +742,64 @@ impl<
         cards
     }
 
+    /// Moves the card from this `Pile` to the bottom of the other one. Either the card is moved,
+    /// or neither `Pile` is changed.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut hand = French::from_str("AS KS").unwrap();
+    /// let mut discards = French::from_str("2C").unwrap();
+    ///
+    /// hand.move_to(&mut discards, &card!(KS)).unwrap();
+    ///
+    /// assert_eq!(hand.to_string(), "A♠");
+    /// assert_eq!(discards.to_string(), "2♣ K♠");
+    /// assert_eq!(
+    ///     hand.move_to(&mut discards, &card!(QS)),
+    ///     Err(CardError::NotInPile("QS".to_string()))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotInPile` if the card isn't in this `Pile`.
+    pub fn move_to(
+        &mut self,
+        other: &mut Pile<RankType, SuitType>,
+        card: &Card<RankType, SuitType>,
+    ) -> Result<(), CardError> {
+        let card = self
+            .remove_card(card)
+            .ok_or_else(|| CardError::NotInPile(card.index.to_string()))?;
+        other.0.push(card);
+        Ok(())
+    }
+
+    /// Returns the card at the bottom of the `Pile` without drawing it.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(French::deck().peek_bottom(), Some(&card!(2C)));
+    /// assert_eq!(Pile::<French, French>::default().peek_bottom(), None);
+    /// ```
+    #[must_use]
+    pub fn peek_bottom(&self) -> Option<&Card<RankType, SuitType>> {
+        self.0.last()
+    }
+
+    /// Returns the card on top of the `Pile` without drawing it.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(French::deck().peek_top(), Some(&card!(AS)));
+    /// ```
+    #[must_use]
+    pub fn peek_top(&self) -> Option<&Card<RankType, SuitType>> {
+        self.0.first()
+    }
+
     /// Returns the zero indexed position of a [`Card`] in the `Pile`.
     ///
     /// ```rust
@@ -430,6 +894,28 @@ impl<
             .map(|ranks| Rank::<RankType>::ranks_index(&ranks, joiner))
     }
 
+    /// Returns the runs of two or more ranks in the `Pile`, regardless of suit, under the passed
+    /// in [`RankProfile`]. Each run is from highest to lowest.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = cards!("5♠ 4♥ 3♠ 2♣ A♦ 9♠").unwrap();
+    ///
+    /// let straights = pile.rank_runs(RankProfile::AceBoth);
+    ///
+    /// assert_eq!(straights.len(), 1);
+    /// assert_eq!(Rank::ranks_index(&straights[0], " "), "5 4 3 2 A");
+    /// assert!(pile.rank_runs(RankProfile::AceHigh)[0].len() == 4);
+    /// ```
+    #[must_use]
+    pub fn rank_runs(&self, profile: RankProfile) -> Vec<Vec<Rank<RankType>>> {
+        profile
+            .runs(&self.ranks())
+            .into_iter()
+            .map(|run| run.into_iter().map(Rank::new).collect())
+            .collect()
+    }
+
     /// ```
     /// use cardpack::prelude::*;
     /// let pile = cards!("A♠ K♠ A♣ Q♣ K♥").unwrap();
@@ -536,6 +1022,43 @@ impl<
         self.0.reverse();
     }
 
+    /// Returns the runs of two or more cards of the same suit with ranks that are next to each
+    /// other under the passed in [`RankProfile`], such as the runs in rummy. Each run is from
+    /// highest to lowest, and they're grouped by suit. Each card is only in one run, even an
+    /// ace under [`RankProfile::AceBoth`]; see [`RankProfile::runs()`] for which one it goes to.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = cards!("2♠ K♥ A♠ 3♠ Q♥ 7♦ 8♣").unwrap();
+    ///
+    /// let runs = pile.runs(RankProfile::AceBoth);
+    ///
+    /// assert_eq!(runs.len(), 2);
+    /// assert_eq!(runs[0].to_string(), "3♠ 2♠ A♠");
+    /// assert_eq!(runs[1].to_string(), "K♥ Q♥");
+    /// assert_eq!(pile.runs(RankProfile::AceHigh)[0].to_string(), "3♠ 2♠");
+    /// ```
+    #[must_use]
+    pub fn runs(&self, profile: RankProfile) -> Vec<Self> {
+        let mut runs: Vec<Self> = Vec::new();
+        for suit in self.suits() {
+            let cards: Vec<&Card<RankType, SuitType>> =
+                self.0.iter().filter(|card| card.suit == suit).collect();
+            let ranks: Vec<Rank<RankType>> = cards.iter().map(|card| card.rank.clone()).collect();
+
+            for run in profile.runs(&ranks) {
+                let run = run.into_iter().filter_map(|name| {
+                    cards
+                        .iter()
+                        .find(|card| card.rank.fluent_name_string() == name)
+                        .map(|card| (*card).clone())
+                });
+                runs.push(Pile(run.collect()));
+            }
+        }
+        runs
+    }
+
     /// Returns true if the Cards of the passed in `Pile` are identical to the `Pile`, regqrdless
     /// of order.
     ///
@@ -549,8 +1072,10 @@ impl<
     /// ```
     #[must_use]
     pub fn same(&self, cards: &Pile<RankType, SuitType>) -> bool {
-        let left = self.sort();
-        let right = cards.sort();
+        let mut left = self.0.clone();
+        let mut right = cards.0.clone();
+        left.sort();
+        right.sort();
 
         left == right
     }
@@ -569,47 +1094,129 @@ impl<
         pile
     }
 
-    /// TODO WIP
-    #[allow(dead_code)]
-    fn shuffle_in_place_custom<F>(&mut self, mut rng: F)
-    where
-        F: FnMut(usize) -> usize,
-    {
-        let mut cards = self.0.clone();
-        let mut shuffled = Vec::new();
-        while !cards.is_empty() {
-            let index = rng(cards.len());
-            shuffled.push(cards.remove(index));
-        }
-        self.0 = shuffled;
-    }
-
+    /// Returns a shuffled copy of the `Pile` using the passed in random number generator.
+    ///
     /// ```
     /// use cardpack::prelude::*;
-    /// let mut pile = French::deck();
-    /// pile.shuffle_in_place();
+    /// use rand::thread_rng;
     ///
-    /// assert!(pile.same(&French::deck()));
+    /// let pile = French::deck();
+    /// let shuffled = pile.shuffle_with_rng(&mut thread_rng());
+    ///
+    /// assert!(pile.same(&shuffled));
     /// ```
-    pub fn shuffle_in_place(&mut self) {
-        let mut rng = thread_rng();
-        self.0.shuffle(&mut rng);
+    #[must_use]
+    pub fn shuffle_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Self {
+        let mut pile = self.clone();
+        pile.shuffle_in_place_with_rng(rng);
+        pile
     }
 
+    /// Returns a shuffled copy of the `Pile` that will always be in the same order for the
+    /// same seed. The shuffle is a [Fisher–Yates](Random::fisher_yates) shuffle over a
+    /// [`SeededRng`](crate::types::utils::SeededRng), and both are stable between platforms and
+    /// releases, which makes it possible to replay the exact same deal in a test or bug report.
+    ///
     /// ```
     /// use cardpack::prelude::*;
     ///
-    /// let pile = FrenchDeck::from_str("K♠ A♠").unwrap();
-    /// let sorted = pile.sort();
+    /// let deck = French::deck();
     ///
-    /// assert_eq!(sorted.to_string(), "A♠ K♠");
+    /// assert_eq!(deck.shuffle_seeded(1_000), deck.shuffle_seeded(1_000));
+    /// assert_ne!(deck.shuffle_seeded(1_000), deck.shuffle_seeded(1_001));
+    /// assert!(deck.same(&deck.shuffle_seeded(1_000)));
     /// ```
     #[must_use]
-    pub fn sort(&self) -> Self {
-        let mut cards: Vec<Card<RankType, SuitType>> = self.0.clone();
-        cards.sort();
-        cards.reverse();
-        Self(cards)
+    pub fn shuffle_seeded(&self, seed: u64) -> Self {
+        let mut pile = self.clone();
+        pile.shuffle_in_place_seeded(seed);
+        pile
+    }
+
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut pile = French::deck();
+    /// pile.shuffle_in_place();
+    ///
+    /// assert!(pile.same(&French::deck()));
+    /// ```
+    pub fn shuffle_in_place(&mut self) {
+        self.shuffle_in_place_with_rng(&mut thread_rng());
+    }
+
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::types::utils::Random;
+    ///
+    /// let mut pile = French::deck();
+    /// pile.shuffle_in_place_with_rng(&mut Random::seeded(52));
+    ///
+    /// assert_eq!(pile, French::deck().shuffle_seeded(52));
+    /// ```
+    pub fn shuffle_in_place_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        Random::fisher_yates(&mut self.0, rng);
+    }
+
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let mut pile = French::deck();
+    /// pile.shuffle_in_place_seeded(52);
+    ///
+    /// assert_eq!(pile, French::deck().shuffle_seeded(52));
+    /// ```
+    pub fn shuffle_in_place_seeded(&mut self, seed: u64) {
+        self.shuffle_in_place_with_rng(&mut Random::seeded(seed));
+    }
+
+    /// Returns a copy of the `Pile` sorted by the [weight](ByWeight) of the cards, highest
+    /// first.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let pile = FrenchDeck::from_str("K♠ A♠").unwrap();
+    /// let sorted = pile.sort();
+    ///
+    /// assert_eq!(sorted.to_string(), "A♠ K♠");
+    /// ```
+    #[must_use]
+    pub fn sort(&self) -> Self {
+        self.sort_by_ordering(&ByWeight)
+    }
+
+    /// Returns a copy of the `Pile` sorted by the passed in [`CardOrdering`], which lets
+    /// games sort their cards in their own way without changing the cards themselves.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::types::ordering::TrumpFirst;
+    ///
+    /// let pile = FrenchDeck::from_str("A♠ K♥ 2♣").unwrap();
+    /// let clubs = TrumpFirst::new(Suit::<French>::from('C'));
+    ///
+    /// assert_eq!(pile.sort_by_ordering(&clubs).to_string(), "2♣ A♠ K♥");
+    /// ```
+    #[must_use]
+    pub fn sort_by_ordering<O: CardOrdering<RankType, SuitType>>(&self, ordering: &O) -> Self {
+        let mut pile = self.clone();
+        pile.sort_by_ordering_in_place(ordering);
+        pile
+    }
+
+    /// Sorts the `Pile` in place by the passed in [`CardOrdering`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::types::ordering::ByRank;
+    ///
+    /// let mut pile = FrenchDeck::from_str("K♠ 2♣ A♥").unwrap();
+    /// pile.sort_by_ordering_in_place(&ByRank);
+    ///
+    /// assert_eq!(pile.to_string(), "A♥ K♠ 2♣");
+    /// ```
+    pub fn sort_by_ordering_in_place<O: CardOrdering<RankType, SuitType>>(&mut self, ordering: &O) {
+        self.0.sort_by(|a, b| ordering.compare(a, b));
     }
 
     /// ```
@@ -621,8 +1228,37 @@ impl<
     /// assert_eq!(pile.to_string(), "A♠ K♠");
     /// ```
     pub fn sort_in_place(&mut self) {
-        self.0.sort();
-        self.0.reverse();
+        self.sort_by_ordering_in_place(&ByWeight);
+    }
+
+    /// Splits the `Pile` into `n` piles of cards that are next to each other, as evenly as
+    /// possible, with any extra cards going to the first piles.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = French::from_str("AS KS QS JS TS").unwrap();
+    ///
+    /// let piles = pile.split_into(2);
+    ///
+    /// assert_eq!(piles[0].to_string(), "A♠ K♠ Q♠");
+    /// assert_eq!(piles[1].to_string(), "J♠ T♠");
+    /// ```
+    #[must_use]
+    pub fn split_into(&self, n: usize) -> Vec<Self> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let size = self.len() / n;
+        let extra = self.len() % n;
+        let mut start = 0;
+        (0..n)
+            .map(|i| {
+                let end = start + size + usize::from(i < extra);
+                let pile = Pile::<RankType, SuitType>::from(self.0[start..end].to_vec());
+                start = end;
+                pile
+            })
+            .collect()
     }
 
     /// Returns a vector of all the [`Suits`](Suit) in the `Pile`.
@@ -698,6 +1334,102 @@ impl<
             .join(" ")
     }
 
+    /// Draws exactly `n` cards from the top of the `Pile`, leaving it alone if there aren't
+    /// enough.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let mut pile = French::from_str("AS KS QS").unwrap();
+    ///
+    /// assert_eq!(pile.try_draw(2).unwrap().to_string(), "A♠ K♠");
+    /// assert_eq!(pile.try_draw(2), Err(CardError::NotEnoughCards(1)));
+    /// assert_eq!(pile.to_string(), "Q♠");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotEnoughCards` if there are fewer than `n` cards in the `Pile`.
+    pub fn try_draw(&mut self, n: usize) -> Result<Self, CardError> {
+        if n > self.len() {
+            return Err(CardError::NotEnoughCards(n - self.len()));
+        }
+        Ok(Pile::<RankType, SuitType>::from(
+            self.0.drain(..n).collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Returns the cards in the `Pile` followed by any cards in the passed in `Pile` that it
+    /// is missing, so that each card has as many copies as the `Pile` with the most of them.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = PinochleDeck::from_str("AS QS QS").unwrap();
+    /// let other = PinochleDeck::from_str("AS AS QS JD").unwrap();
+    ///
+    /// assert_eq!(pile.union(&other).to_string(), "A♠ Q♠ Q♠ A♠ J♦");
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Pile<RankType, SuitType>) -> Self {
+        let mut pile = self.clone();
+        pile.extend(&other.difference(self));
+        pile
+    }
+
+    /// Validates that every [`Card`] in the `Pile` could have been dealt from the passed in deck,
+    /// with no more copies of any `Card` than the deck has. Cards are compared by their index.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let deck = Pinochle::deck();
+    ///
+    /// assert!(Pinochle::from_str("AS AS").unwrap().validate_against(&deck).is_ok());
+    /// assert_eq!(
+    ///     Pinochle::from_str("AS AS AS").unwrap().validate_against(&deck),
+    ///     Err(CardError::TooManyCopies("AS".to_string()))
+    /// );
+    ///
+    /// let short = French::from_str("AS KS QS").unwrap();
+    /// assert_eq!(
+    ///     French::from_str("AS AS 2S").unwrap().validate_against(&short),
+    ///     Err(CardError::NotInDeckAndTooManyCopies("2S".to_string(), "AS".to_string()))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotInDeck` with the indexes of the cards that aren't in the deck,
+    /// `CardError::TooManyCopies` with the indexes of the cards that there are too many of, or
+    /// `CardError::NotInDeckAndTooManyCopies` with both if there are both kinds.
+    pub fn validate_against(&self, deck: &Pile<RankType, SuitType>) -> Result<(), CardError> {
+        let mut allowed: HashMap<&str, usize> = HashMap::new();
+        for card in &deck.0 {
+            *allowed.entry(card.index).or_default() += 1;
+        }
+
+        let mut foreign: Vec<&str> = Vec::new();
+        let mut duplicated: Vec<&str> = Vec::new();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for card in &self.0 {
+            let count = counts.entry(card.index).or_default();
+            *count += 1;
+            match allowed.get(card.index) {
+                None if *count == 1 => foreign.push(card.index),
+                Some(max) if *count == max + 1 => duplicated.push(card.index),
+                _ => {}
+            }
+        }
+
+        match (foreign.is_empty(), duplicated.is_empty()) {
+            (true, true) => Ok(()),
+            (false, true) => Err(CardError::NotInDeck(foreign.join(" "))),
+            (true, false) => Err(CardError::TooManyCopies(duplicated.join(" "))),
+            (false, false) => Err(CardError::NotInDeckAndTooManyCopies(
+                foreign.join(" "),
+                duplicated.join(" "),
+            )),
+        }
+    }
+
     /// Returns the Pile's internal vector of [`Cards`](Card).
     ///
     /// ```rust
@@ -713,6 +1445,17 @@ impl<
     pub fn v(&self) -> &Vec<Card<RankType, SuitType>> {
         &self.0
     }
+
+    /// ```
+    /// use cardpack::prelude::*;
+    /// let pile = French::from_str("AS KS QS").unwrap();
+    ///
+    /// assert_eq!(pile.as_slice()[1..], [card!(KS), card!(QS)]);
+    /// ```
+    #[must_use]
+    pub fn as_slice(&self) -> &[Card<RankType, SuitType>] {
+        &self.0
+    }
 }
 
 impl<
@@ -731,6 +1474,16 @@ impl<
     }
 }
 
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > Shufflable<RankType, SuitType> for Pile<RankType, SuitType>
+{
+    fn cards_mut(&mut self) -> &mut Vec<Card<RankType, SuitType>> {
+        &mut self.0
+    }
+}
+
 impl<
         RankType: Ranked + Ord + Clone + Default + Hash,
         SuitType: Suited + Ord + Clone + Default + Hash,
@@ -786,8 +1539,26 @@ impl<
 /// **ASIDE:** This is probably my biggest embarrassment when coding this library the first time. I had no
 /// idea that this trait existed, and bent over backwards trying to duplicate its functionality.
 ///
-/// TODO: Add a step that validates that the cards are of the correct number for the type of deck.
-/// Perhaps using the `Decked::deck()` method.
+/// A `Pile` doesn't know which deck it came from, so parsing only checks that each card is valid
+/// for its [`Ranked`] and [`Suited`] types. Use
+/// [`Decked::validate()`](crate::types::traits::Decked::validate) to check that the cards, and
+/// the number of copies of each, are valid for a specific type of deck.
+///
+/// If any of the cards can't be parsed, the error is a `CardError::InvalidPile` with the
+/// [`Diagnostics`] for every one of them, the same as [`Pile::diagnose()`]:
+///
+/// ```rust
+/// use cardpack::prelude::*;
+///
+/// let Err(CardError::InvalidPile(diagnostics)) = Pile::<French, French>::from_str("AS 1H KX")
+/// else {
+///     panic!("1H and KX aren't cards");
+/// };
+///
+/// assert_eq!(diagnostics.len(), 2);
+/// assert_eq!(diagnostics.v()[0].position, 1);
+/// assert_eq!(diagnostics.v()[1].reason, ParseReason::UnknownSuit("X".to_string()));
+/// ```
 impl<
         RankType: Ranked + Ord + Clone + Default + Hash,
         SuitType: Suited + Ord + Clone + Default + Hash,
@@ -796,18 +1567,7 @@ impl<
     type Err = CardError;
 
     fn from_str(index: &str) -> Result<Self, Self::Err> {
-        let mut cards = Pile::<RankType, SuitType>::default();
-        for s in index.split_whitespace() {
-            if !cards.push(Card::<RankType, SuitType>::from_str(s)?) {
-                return Err(CardError::InvalidIndex(s.to_string()));
-            }
-        }
-
-        if cards.is_empty() {
-            Err(CardError::InvalidIndex(index.to_string()))
-        } else {
-            Ok(cards)
-        }
+        Ok(Pile::diagnose(index)?)
     }
 }
 
@@ -843,26 +1603,109 @@ impl<
     }
 }
 
-/// For a `Pile` reference, the implementation of the trait will internally `clone()` the `Cards`.
+/// For a `Pile` reference, the cards are borrowed, the same as [`Pile::iter()`].
 ///
 /// ```rust
 /// use cardpack::prelude::*;
 /// let pile = French::deck();
 ///
 /// for card in &pile {
-///    assert!(pile.contains(&card));
+///    assert!(pile.contains(card));
 /// }
 /// ```
 impl<
+        'a,
         RankType: Ranked + Ord + Clone + Default + Hash,
         SuitType: Suited + Ord + Clone + Default + Hash,
-    > IntoIterator for &Pile<RankType, SuitType>
+    > IntoIterator for &'a Pile<RankType, SuitType>
 {
-    type Item = Card<RankType, SuitType>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type Item = &'a Card<RankType, SuitType>;
+    type IntoIter = std::slice::Iter<'a, Card<RankType, SuitType>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        <Vec<Card<RankType, SuitType>> as Clone>::clone(&self.0).into_iter()
+        self.0.iter()
+    }
+}
+
+impl<
+        'a,
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > IntoIterator for &'a mut Pile<RankType, SuitType>
+{
+    type Item = &'a mut Card<RankType, SuitType>;
+    type IntoIter = std::slice::IterMut<'a, Card<RankType, SuitType>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+/// ```rust
+/// use cardpack::prelude::*;
+/// let pile: Pile<French, French> = French::deck().into_iter().filter(|card| card.rank == Rank::from('A')).collect();
+///
+/// assert_eq!(pile.to_string(), "A♠ A♥ A♦ A♣");
+/// ```
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > FromIterator<Card<RankType, SuitType>> for Pile<RankType, SuitType>
+{
+    fn from_iter<I: IntoIterator<Item = Card<RankType, SuitType>>>(iter: I) -> Self {
+        Pile(iter.into_iter().collect())
+    }
+}
+
+/// Since `Pile` has its own [`Pile::extend()`] method that takes another `Pile`, this needs to be
+/// called through the trait when extending from an iterator.
+///
+/// ```rust
+/// use cardpack::prelude::*;
+/// let mut pile = French::from_str("AS").unwrap();
+///
+/// Extend::extend(&mut pile, French::from_str("KS QS").unwrap().iter().copied());
+///
+/// assert_eq!(pile.to_string(), "A♠ K♠ Q♠");
+/// ```
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > Extend<Card<RankType, SuitType>> for Pile<RankType, SuitType>
+{
+    fn extend<I: IntoIterator<Item = Card<RankType, SuitType>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+/// ```rust
+/// use cardpack::prelude::*;
+/// let pile = French::deck();
+///
+/// assert_eq!(pile[0], card!(AS));
+/// assert_eq!(pile[50..].to_vec(), vec![card!(3C), card!(2C)]);
+/// ```
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+        I: SliceIndex<[Card<RankType, SuitType>]>,
+    > Index<I> for Pile<RankType, SuitType>
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+        I: SliceIndex<[Card<RankType, SuitType>]>,
+    > IndexMut<I> for Pile<RankType, SuitType>
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.0[index]
     }
 }
 
@@ -870,7 +1713,10 @@ impl<
 #[allow(non_snake_case)]
 mod types__pile__tests {
     use super::*;
+    use crate::decks::canasta::Canasta;
     use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::decks::pinochle::Pinochle;
     use crate::types::traits::Decked;
     use std::str::FromStr;
 
@@ -924,6 +1770,22 @@ mod types__pile__tests {
         assert_eq!(pile.get(5).unwrap().index, "9D");
     }
 
+    #[test]
+    fn extend__iter() {
+        let mut pile = test_pile();
+        Extend::extend(&mut pile, French::deck().iter().take(2).copied());
+
+        assert_eq!(pile.len(), 6);
+        assert_eq!(pile[4..].to_vec(), French::deck()[..2].to_vec());
+    }
+
+    #[test]
+    fn from_iter() {
+        let pile: Pile<French, French> = test_pile().into_iter().rev().collect();
+
+        assert_eq!(pile.index(), "AS AH TD 2S");
+    }
+
     #[test]
     fn get() {
         let pile = test_pile();
@@ -935,6 +1797,17 @@ mod types__pile__tests {
         assert!(pile.get(4).is_none());
     }
 
+    #[test]
+    fn index() {
+        let mut pile = test_pile();
+        pile[0].weight = 0;
+
+        assert_eq!(pile[1].index, "TD");
+        assert_eq!(pile[0].weight, 0);
+        assert_eq!(pile[..2].len(), 2);
+        assert_eq!(pile.as_slice(), pile.v().as_slice());
+    }
+
     #[test]
     fn is_empty() {
         let mut pile = Pile::<French, French>::default();
@@ -965,6 +1838,27 @@ mod types__pile__tests {
         );
     }
 
+    #[test]
+    fn iter__borrowed() {
+        let pile = test_pile();
+        let first: &Card<French, French> = pile.iter().next().unwrap();
+
+        assert!(std::ptr::eq(first, &pile[0]));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut pile = test_pile();
+        for card in &mut pile {
+            card.weight += 1;
+        }
+
+        assert!(pile
+            .iter()
+            .zip(test_pile().iter())
+            .all(|(a, b)| a.weight == b.weight + 1));
+    }
+
     #[test]
     fn len() {
         assert_eq!(Pile::<French, French>::default().len(), 0);
@@ -1016,6 +1910,73 @@ mod types__pile__tests {
         assert_eq!(removed_from_deck.unwrap().index, "AH");
     }
 
+    #[test]
+    fn cut__whole_pile() {
+        let mut pile = test_pile();
+
+        pile.cut(pile.len()).unwrap();
+        assert_eq!(pile, test_pile());
+        pile.cut(0).unwrap();
+        assert_eq!(pile, test_pile());
+    }
+
+    #[test]
+    fn draw_random_with_rng() {
+        let mut pile = French::deck();
+
+        let drawn = pile
+            .draw_random_with_rng(52, &mut Random::seeded(1))
+            .unwrap();
+
+        assert!(pile.is_empty());
+        assert!(drawn.same(&French::deck()));
+        assert_ne!(drawn, French::deck());
+    }
+
+    #[test]
+    fn draw_random_with_rng__keeps_the_order() {
+        let mut pile = French::deck();
+
+        let drawn = pile
+            .draw_random_with_rng(10, &mut Random::seeded(1))
+            .unwrap();
+
+        assert_eq!(drawn.len(), 10);
+        assert_eq!(pile.len(), 42);
+        assert_eq!(pile, French::deck().difference(&drawn));
+    }
+
+    #[test]
+    fn move_to__not_in_pile() {
+        let mut pile = test_pile();
+        let mut other = Pile::<French, French>::default();
+
+        let result = pile.move_to(&mut other, &Card::from_str("2C").unwrap());
+
+        assert_eq!(result, Err(CardError::NotInPile("2C".to_string())));
+        assert_eq!(pile, test_pile());
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn split_into() {
+        let piles = French::deck().split_into(5);
+        let sizes: Vec<usize> = piles.iter().map(Pile::len).collect();
+
+        assert_eq!(sizes, vec![11, 11, 10, 10, 10]);
+        assert_eq!(Pile::pile_on(&piles), French::deck());
+        assert_eq!(test_pile().split_into(6).last().unwrap().len(), 0);
+        assert!(test_pile().split_into(0).is_empty());
+    }
+
+    #[test]
+    fn try_draw__empty() {
+        let mut pile = Pile::<French, French>::default();
+
+        assert_eq!(pile.try_draw(0), Ok(Pile::default()));
+        assert_eq!(pile.try_draw(1), Err(CardError::NotEnoughCards(1)));
+    }
+
     #[test]
     fn same() {
         let deck = French::deck();
@@ -1037,9 +1998,147 @@ mod types__pile__tests {
         assert!(!alt.same(&deck));
     }
 
+    #[test]
+    fn shuffle_seeded() {
+        let deck = French::deck();
+
+        assert_eq!(deck.shuffle_seeded(42), deck.shuffle_seeded(42));
+        assert_ne!(deck.shuffle_seeded(42), deck.shuffle_seeded(43));
+        assert!(deck.same(&deck.shuffle_seeded(42)));
+    }
+
+    /// If this test breaks, every recorded seed in every regression test and bug report will
+    /// stop replaying the same deal. Don't update the expected values without a very good reason.
+    #[test]
+    fn shuffle_seeded__stable() {
+        assert_eq!(
+            French::deck().shuffle_seeded(42).index(),
+            "TD KH 8H 5H 8C 3D JS 9C 2H 9S 4D TH 8S 5D 5S 5C 2C 9H QS QH QD 6H 7D 3H KS 7S 2S TS AD 9D 4S JC 6S 3S KC AH AC 4C 2D JD 4H 3C 6D 6C 7C JH KD 8D AS 7H TC QC"
+        );
+        assert_eq!(
+            Pile::<French, French>::from_str("AS KS QS JS TS")
+                .unwrap()
+                .shuffle_seeded(0)
+                .index(),
+            "TS AS KS JS QS"
+        );
+    }
+
+    #[test]
+    fn contains__reweighted() {
+        let mut deck = Canasta::deck();
+        let three_of_hearts = Card::<Modern, Modern>::from_str("3H").unwrap();
+
+        assert!(deck.contains(&three_of_hearts));
+        assert_eq!(
+            deck.remove_card(&three_of_hearts).unwrap().weight,
+            Canasta::three_of_hearts().weight
+        );
+        assert_eq!(deck.len(), 107);
+    }
+
+    #[test]
+    fn counts() {
+        let counts = Pinochle::deck().counts();
+
+        assert_eq!(counts.len(), 48);
+        assert_eq!(counts.set_len(), 24);
+        assert!(counts.set_iter().all(|(_, count)| count == 2));
+    }
+
+    #[test]
+    fn difference() {
+        let deck = Canasta::deck();
+        let hand = deck.clone().draw(30);
+
+        let rest = deck.difference(&hand);
+
+        assert_eq!(rest.len(), deck.len() - 30);
+        assert!(rest.union(&hand).same(&deck));
+        assert!(deck.difference(&deck).is_empty());
+    }
+
+    #[test]
+    fn intersection() {
+        let deck = Pinochle::deck();
+        let single = Pile::<Pinochle, French>::from_str("AS TS KS QS JS 9S").unwrap();
+
+        assert_eq!(deck.intersection(&single), single);
+        assert_eq!(single.intersection(&deck), single);
+        assert!(deck.intersection(&Pile::default()).is_empty());
+    }
+
+    #[test]
+    fn is_subset() {
+        let deck = Pinochle::deck();
+        let hand = Pile::<Pinochle, French>::from_str("AS AS").unwrap();
+        let too_many = Pile::<Pinochle, French>::from_str("AS AS AS").unwrap();
+
+        assert!(hand.is_subset(&deck));
+        assert!(deck.is_subset(&deck));
+        assert!(Pile::<Pinochle, French>::default().is_subset(&deck));
+        assert!(!too_many.is_subset(&deck));
+        assert!(!deck.is_subset(&hand));
+    }
+
+    #[test]
+    fn union() {
+        let deck = Pinochle::deck();
+
+        assert_eq!(deck.union(&deck), deck);
+        assert_eq!(deck.union(&Pile::from(deck.v()[..24].to_vec())), deck);
+    }
+
+    #[test]
+    fn runs__duplicates() {
+        let pile = Pile::<Pinochle, French>::from_str("AS AS TS KS KS 9S").unwrap();
+
+        let runs = pile.runs(RankProfile::AceHigh);
+
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].to_string(), "A♠ T♠ K♠");
+    }
+
+    #[test]
+    fn shufflable__shuffle() {
+        let mut pile = French::deck();
+
+        Shufflable::shuffle(&mut pile, |v| v.reverse());
+
+        assert!(pile.same(&French::deck()));
+        assert_eq!(pile.draw_first().unwrap().to_string(), "2♣");
+    }
+
+    #[test]
+    fn shufflable__faro_out() {
+        let mut pile = French::deck();
+
+        for _ in 0..8 {
+            pile.faro_out();
+        }
+
+        assert_eq!(pile, French::deck());
+    }
+
+    #[test]
+    fn shufflable__models() {
+        let mut rng = Random::seeded(5);
+        let deck = French::deck();
+        let mut pile = deck.clone();
+
+        pile.riffles(7, &mut rng);
+        pile.overhand(&mut rng);
+        pile.strip(&mut rng);
+        pile.pile_shuffle(4, &mut rng);
+        pile.random_cut(&mut rng);
+
+        assert!(pile.same(&deck));
+        assert_ne!(pile, deck);
+    }
+
     #[test]
     fn to_color_symbol_string() {
-        let expected = vec![
+        let expected = [
             Card::<French, French>::from_str("2S").unwrap().to_string(),
             Card::<French, French>::from_str("TD")
                 .unwrap()
@@ -1068,4 +2167,54 @@ mod types__pile__tests {
         assert!(Pile::<French, French>::from_str("2S TD AH AS 2X").is_err());
         assert!(Pile::<French, French>::from_str("   ").is_err());
     }
+
+    #[test]
+    fn from_str__invalid_pile() {
+        let error = Pile::<French, French>::from_str("2S TD AH AS 2X 11").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid Pile: `2X` at 12..14: unknown suit `X` (did you mean `2S`, `2H`, `2D`, `2C`?); \
+             `11` at 15..17: not a card"
+        );
+        let CardError::InvalidPile(diagnostics) = error else {
+            panic!("expected CardError::InvalidPile");
+        };
+        assert_eq!(diagnostics.v()[0].position, 4);
+        assert_eq!(diagnostics.v()[1].position, 5);
+    }
+
+    #[test]
+    fn long() {
+        let lid = FluentName::US_ENGLISH;
+
+        assert_eq!(Pile::<French, French>::default().long(&lid), "");
+        assert_eq!(French::from_str("AS").unwrap().long(&lid), "Ace of Spades");
+        assert_eq!(
+            French::from_str("AS KH QH AS").unwrap().long(&lid),
+            "Ace of Spades, King and Queen of Hearts and Ace of Spades"
+        );
+        assert_eq!(
+            Modern::from_str("BJ AS KS").unwrap().long(&lid),
+            "Full-Color Joker and Ace and King of Spades"
+        );
+        assert_eq!(
+            French::from_str("TD 2D")
+                .unwrap()
+                .long(&FluentName::DEUTSCH),
+            "Zehn und Zwei von Diamanten"
+        );
+    }
+
+    #[test]
+    fn index_localized__round_trip() {
+        let deck = French::deck();
+        let index = deck.index_localized(&FluentName::DEUTSCH);
+
+        assert_ne!(index, deck.index());
+        assert_eq!(
+            Pile::<French, French>::from_str_localized(&index, &FluentName::DEUTSCH).unwrap(),
+            deck
+        );
+    }
 }
@@ -1,17 +1,34 @@
-use crate::decks::french::French;
-use crate::decks::modern::Modern;
-use crate::decks::pinochle::Pinochle;
-use crate::decks::skat::Skat;
-use crate::decks::tarot::Tarot;
 use crate::localization::{FluentName, Named};
 use crate::types::card_error::CardError;
+use crate::types::rank_profile::RankProfile;
 use crate::types::traits::Ranked;
 use crate::types::utils::Bit;
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// A `Rank` is identified by its name alone, so it's equal to, and hashes the same as, any
+/// other `Rank` with the same name, whatever their `weight` and `prime`. That way a card from
+/// [`Rank::new_with_weight()`], [`Rank::ranks_from_array()`] or a
+/// [`RankProfile`] is still the same card. For the same reason, ranks are ordered by where
+/// their names are in the deck's [`special_rank_names()`](Ranked::special_rank_names) and then
+/// its [`rank_names()`](Ranked::rank_names), highest first, and not by `weight`. Ranks that
+/// aren't the deck's come last, ordered by name. Use a
+/// [`CardOrdering`](crate::types::traits::CardOrdering), such as
+/// [`ByWeight`](crate::types::ordering::ByWeight), to sort by weight.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let low_ace = Rank::<French>::new_with_weight(French::ACE, 0);
+///
+/// assert_eq!(low_ace, Rank::<French>::new(French::ACE));
+/// assert!(low_ace > Rank::<French>::new(French::KING));
+/// assert!(Rank::<French>::new(French::ACE) > Rank::<French>::new(French::KING));
+/// ```
+#[derive(Clone, Copy, Debug)]
 pub struct Rank<RankType>
 where
     RankType: Ranked,
@@ -22,6 +39,40 @@ where
     pub phantom_data: PhantomData<RankType>,
 }
 
+impl<RankType: Ranked> PartialEq for Rank<RankType> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<RankType: Ranked> Eq for Rank<RankType> {}
+
+impl<RankType: Ranked> Hash for Rank<RankType> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl<RankType: Ranked> PartialOrd for Rank<RankType> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<RankType: Ranked> Ord for Rank<RankType> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.name == other.name {
+            return Ordering::Equal;
+        }
+        match (self.position(), other.position()) {
+            (Some(position), Some(other_position)) => other_position.cmp(&position),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => self.name.cmp(&other.name),
+        }
+    }
+}
+
 impl<RankType> Rank<RankType>
 where
     RankType: Ranked,
@@ -31,13 +82,48 @@ where
         let name = FluentName::new(name_str);
 
         Rank::<RankType> {
-            weight: name.weight(),
+            weight: RankType::rank_weight(name_str),
             prime: name.prime(),
             name,
             phantom_data: PhantomData,
         }
     }
 
+    /// The rank for one of the deck's [rank chars](Ranked::rank_chars). Where
+    /// `Rank::from(char)` gives a blank rank for a char that isn't one of them, this says so.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(Rank::<French>::from_char('K').unwrap(), Rank::new(French::KING));
+    /// assert_eq!(
+    ///     Rank::<French>::from_char('X').unwrap_err(),
+    ///     CardError::InvalidFluentRank("X".to_string())
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::InvalidFluentRank` if the char isn't one of the deck's ranks.
+    pub fn from_char(c: char) -> Result<Rank<RankType>, CardError> {
+        let rank = Rank::from(c);
+        if rank.is_blank() {
+            Err(CardError::InvalidFluentRank(c.to_string()))
+        } else {
+            Ok(rank)
+        }
+    }
+
+    /// Where the rank's name is in the deck's special ranks and then its regular ones, which is
+    /// what ranks are ordered by.
+    fn position(&self) -> Option<usize> {
+        let name = self.name.fluent_name_string();
+        RankType::special_rank_names()
+            .into_iter()
+            .chain(RankType::rank_names())
+            .position(|rank| rank == name)
+    }
+
     #[must_use]
     pub fn new_with_weight(name_str: &str, weight: u32) -> Rank<RankType> {
         let name = FluentName::new(name_str);
@@ -58,6 +144,22 @@ where
             .collect()
     }
 
+    /// The ranks of the deck's special suits, such as the major arcana of a tarot deck.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(Rank::<Tarot>::special_ranks().len(), 22);
+    /// assert!(Rank::<French>::special_ranks().is_empty());
+    /// ```
+    #[must_use]
+    pub fn special_ranks() -> Vec<Self> {
+        RankType::special_rank_names()
+            .iter()
+            .map(|name| Self::new(name))
+            .collect()
+    }
+
     #[must_use]
     pub fn ranks_from_array(names: &[&'static str]) -> Vec<Self> {
         let mut v: Vec<Self> = Vec::new();
@@ -101,9 +203,60 @@ where
         self.weight << 8
     }
 
+    /// Returns the next higher `Rank` under the passed in [`RankProfile`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let ace = Rank::<French>::from('A');
+    ///
+    /// assert_eq!(ace.next(RankProfile::AceHigh), None);
+    /// assert_eq!(ace.next(RankProfile::AceBoth), Some(Rank::from('2')));
+    /// assert_eq!(Rank::<French>::from('Q').next(RankProfile::AceHigh), Some(Rank::from('K')));
+    /// ```
+    #[must_use]
+    pub fn next(&self, profile: RankProfile) -> Option<Self> {
+        profile.next(self)
+    }
+
+    /// Returns the next lower `Rank` under the passed in [`RankProfile`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let ace = Rank::<French>::from('A');
+    ///
+    /// assert_eq!(ace.prev(RankProfile::AceHigh), Some(Rank::from('K')));
+    /// assert_eq!(ace.prev(RankProfile::AceLow), None);
+    /// ```
+    #[must_use]
+    pub fn prev(&self, profile: RankProfile) -> Option<Self> {
+        profile.prev(self)
+    }
+
+    /// Returns true if the passed in `Rank` is right above or below this one under the
+    /// passed in [`RankProfile`].
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let ace = Rank::<French>::from('A');
+    /// let deuce = Rank::<French>::from('2');
+    ///
+    /// assert!(!ace.is_adjacent(&deuce, RankProfile::AceHigh));
+    /// assert!(ace.is_adjacent(&deuce, RankProfile::AceLow));
+    /// assert!(ace.is_adjacent(&deuce, RankProfile::AceBoth));
+    /// assert!(ace.is_adjacent(&Rank::from('K'), RankProfile::AceBoth));
+    /// ```
+    #[must_use]
+    pub fn is_adjacent(&self, other: &Self, profile: RankProfile) -> bool {
+        let same = |rank: Option<Self>| rank.is_some_and(|rank| rank.name == other.name);
+        same(self.next(profile)) || same(self.prev(profile))
+    }
+
     #[must_use]
     pub fn update_weight(&self, weight: u32) -> Self {
-        Self::new_with_weight(self.fluent_name_string().as_str(), weight)
+        Self::new_with_weight(self.fluent_name_string(), weight)
     }
 }
 
@@ -149,7 +302,7 @@ where
         &self.name
     }
 
-    fn fluent_name_string(&self) -> &String {
+    fn fluent_name_string(&self) -> &'static str {
         self.name.fluent_name_string()
     }
 
@@ -167,11 +320,29 @@ impl<RankType: Ranked> Ranked for Rank<RankType> {
         RankType::rank_names()
     }
 
+    fn special_rank_names() -> Vec<&'static str> {
+        RankType::special_rank_names()
+    }
+
+    fn rank_weight(name: &str) -> u32 {
+        RankType::rank_weight(name)
+    }
+
+    fn run_names() -> Vec<&'static str> {
+        RankType::run_names()
+    }
+
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        RankType::rank_for_char(c)
+    }
+
     fn type_name() -> &'static str {
         RankType::type_name()
     }
 }
 
+/// Returns a blank rank if the char isn't one of the deck's. Use [`Rank::from_char()`] to get
+/// an error instead.
 impl<RankType: Ranked> From<char> for Rank<RankType> {
     fn from(c: char) -> Self {
         if !RankType::is_valid_rank_char(&c) {
@@ -182,84 +353,9 @@ impl<RankType: Ranked> From<char> for Rank<RankType> {
                 phantom_data: PhantomData,
             };
         }
-        match RankType::type_name() {
-            Skat::DECK_NAME => match c {
-                'D' | 'd' => Rank::new(Skat::DAUS),
-                'T' | 't' | '0' => Rank::new(Skat::ZHEN),
-                'K' | 'k' => Rank::new(Skat::KÖNIG),
-                'O' | 'o' => Rank::new(Skat::OBER),
-                'U' | 'u' => Rank::new(Skat::UNTER),
-                '9' => Rank::new(Skat::NEUN),
-                '8' => Rank::new(Skat::ACHT),
-                '7' => Rank::new(Skat::SIEBEN),
-                _ => Rank::new(FluentName::BLANK),
-            },
-            Pinochle::DECK_NAME => match c {
-                'A' | 'a' => Rank::new(Pinochle::ACE),
-                'T' | 't' => Rank::new(Pinochle::TEN),
-                'K' | 'k' => Rank::new(Pinochle::KING),
-                'Q' | 'q' => Rank::new(Pinochle::QUEEN),
-                'J' | 'j' => Rank::new(Pinochle::JACK),
-                '9' => Rank::new(Pinochle::NINE),
-                _ => Rank::new(FluentName::BLANK),
-            },
-            Tarot::DECK_NAME => match c {
-                '2' => Rank::new(French::TWO),
-                '3' => Rank::new(French::THREE),
-                '4' => Rank::new(French::FOUR),
-                '5' => Rank::new(French::FIVE),
-                '6' => Rank::new(French::SIX),
-                '7' => Rank::new(French::SEVEN),
-                '8' => Rank::new(French::EIGHT),
-                '9' => Rank::new(French::NINE),
-                'T' | 't' | '0' => Rank::new(French::TEN),
-                'P' | 'p' => Rank::new(Tarot::PAGE),
-                'J' | 'j' => Rank::new(Tarot::KNIGHT),
-                'Q' | 'q' => Rank::new(French::QUEEN),
-                'K' | 'k' => Rank::new(French::KING),
-                'A' | 'a' => Rank::new(French::ACE),
-                '🤡' => Rank::new(Tarot::FOOL),
-                '🧙' => Rank::new(Tarot::MAGICIAN),
-                '😇' => Rank::new(Tarot::PRIESTESS),
-                '👑' => Rank::new(Tarot::EMPRESS),
-                '🤴' => Rank::new(Tarot::EMPEROR),
-                '🧎' => Rank::new(Tarot::HIEROPHANT),
-                '💏' => Rank::new(Tarot::LOVERS),
-                '🏎' => Rank::new(Tarot::CHARIOT),
-                '💪' => Rank::new(Tarot::STRENGTH),
-                '💡' => Rank::new(Tarot::HERMIT),
-                '🍀' => Rank::new(Tarot::FORTUNE),
-                '⚖' => Rank::new(Tarot::JUSTICE),
-                '🙃' => Rank::new(Tarot::HANGED),
-                '💀' => Rank::new(Tarot::DEATH),
-                '🚭' => Rank::new(Tarot::TEMPERANCE),
-                '😈' => Rank::new(Tarot::DEVIL),
-                '🏢' => Rank::new(Tarot::TOWER),
-                '⭐' => Rank::new(Tarot::STAR),
-                '🌙' => Rank::new(Tarot::MOON),
-                '🌞' => Rank::new(Tarot::SUN),
-                '🔔' => Rank::new(Tarot::JUDGEMENT),
-                '🌍' => Rank::new(Tarot::WORLD),
-                _ => Rank::new(FluentName::BLANK),
-            },
-            _ => match c {
-                '2' => Rank::new(French::TWO),
-                '3' => Rank::new(French::THREE),
-                '4' => Rank::new(French::FOUR),
-                '5' => Rank::new(French::FIVE),
-                '6' => Rank::new(French::SIX),
-                '7' => Rank::new(French::SEVEN),
-                '8' => Rank::new(French::EIGHT),
-                '9' => Rank::new(French::NINE),
-                'T' | 't' | '0' => Rank::new(French::TEN),
-                'J' | 'j' => Rank::new(French::JACK),
-                'Q' | 'q' => Rank::new(French::QUEEN),
-                'K' | 'k' => Rank::new(French::KING),
-                'A' | 'a' => Rank::new(French::ACE),
-                'B' | 'b' => Rank::new(Modern::BIG),
-                'L' | 'l' => Rank::new(Modern::LITTLE),
-                _ => Rank::new(FluentName::BLANK),
-            },
+        match RankType::rank_for_char(&c) {
+            Some(name) => Rank::new(name),
+            None => Rank::new(FluentName::BLANK),
         }
     }
 }
@@ -268,16 +364,10 @@ impl<RankType: Ranked> FromStr for Rank<RankType> {
     type Err = CardError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(first_char) = s.chars().next() {
-            let rank = Rank::<RankType>::from(first_char);
-            if rank.name.is_blank() {
-                Err(CardError::InvalidFluentRank(s.to_string()))
-            } else {
-                Ok(rank)
-            }
-        } else {
-            Err(CardError::InvalidFluentRank(s.to_string()))
-        }
+        s.chars()
+            .next()
+            .and_then(|c| Rank::<RankType>::from_char(c).ok())
+            .ok_or_else(|| CardError::InvalidFluentRank(s.to_string()))
     }
 }
 
@@ -286,9 +376,14 @@ impl<RankType: Ranked> FromStr for Rank<RankType> {
 mod types__rank__tests {
     use super::*;
     use crate::card;
+    use crate::decks::french::French;
+    use crate::decks::pinochle::Pinochle;
+    use crate::decks::skat::Skat;
+    use crate::decks::tarot::Tarot;
     use crate::types::card::Card;
     use crate::types::utils::Bit;
     use ckc_rs::CardNumber;
+    use std::collections::BTreeSet;
 
     #[test]
     fn get_bits() {
@@ -339,6 +434,15 @@ mod types__rank__tests {
         assert_eq!(rank.prime, 41);
     }
 
+    #[test]
+    fn from_char__invalid() {
+        assert_eq!(
+            Rank::<French>::from_char('O'),
+            Err(CardError::InvalidFluentRank("O".to_string()))
+        );
+        assert_eq!(Rank::<Skat>::from_char('O'), Ok(Rank::new(Skat::OBER)));
+    }
+
     #[test]
     fn from_str() {
         let rank = Rank::<French>::from_str("A'").unwrap();
@@ -360,4 +464,40 @@ mod types__rank__tests {
             panic!("Expected CardError::InvalidFluentRank");
         }
     }
+
+    #[test]
+    fn ord__ignores_weight() {
+        let low_ace = Rank::<French>::new_with_weight(French::ACE, 0);
+        let ace = Rank::<French>::new(French::ACE);
+        let king = Rank::<French>::new(French::KING);
+
+        let set: BTreeSet<_> = [low_ace, king, ace].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![king, ace]);
+
+        let mut ranks = vec![ace, king, low_ace, Rank::new(French::TWO), king];
+        ranks.sort();
+        assert_eq!(
+            ranks,
+            vec![Rank::new(French::TWO), king, king, ace, low_ace]
+        );
+    }
+
+    #[test]
+    fn ord__special_ranks_first() {
+        assert!(Rank::<Tarot>::new(Tarot::WORLD) > Rank::<Tarot>::new(French::KING));
+        assert!(Rank::<Tarot>::new(Tarot::FOOL) > Rank::<Tarot>::new(Tarot::WORLD));
+        assert!(Rank::<French>::new(French::TWO) > Rank::<French>::default());
+    }
+
+    #[test]
+    fn from__char__other_decks() {
+        assert_eq!(Rank::<French>::from('t'), Rank::new(French::TEN));
+        assert_eq!(Rank::<Skat>::from('O'), Rank::new(Skat::OBER));
+        assert_eq!(Rank::<Pinochle>::from('0'), Rank::new(Pinochle::TEN));
+        assert_eq!(Rank::<Tarot>::from('J'), Rank::new(Tarot::KNIGHT));
+        assert_eq!(Rank::<Tarot>::from('🌞'), Rank::new(Tarot::SUN));
+        assert!(Rank::<French>::from('O').is_blank());
+        assert!(Rank::<French>::from('🌞').is_blank());
+    }
 }
@@ -0,0 +1,318 @@
+use crate::localization::{FluentName, Named};
+use crate::types::card::Card;
+use crate::types::rank::Rank;
+use crate::types::traits::{CardOrdering, Ranked, Suited};
+use std::cmp::Ordering;
+
+/// How the ranks of a deck are ordered when working out which ranks are next to each other,
+/// such as for straights in poker or runs in rummy, and when sorting cards by rank.
+///
+/// A profile works on the [`run_names()`](Ranked::run_names) of any [`Ranked`] deck, which
+/// list the ranks from highest to lowest, and treats the first one as the ace. A deck's `Rank`
+/// weights are never changed, so a card is still the same card under any profile.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// assert_eq!(RankProfile::AceHigh.index::<French>(), "A K Q J T 9 8 7 6 5 4 3 2");
+/// assert_eq!(RankProfile::AceLow.index::<French>(), "K Q J T 9 8 7 6 5 4 3 2 A");
+/// assert_eq!(RankProfile::AceBoth.index::<French>(), "A K Q J T 9 8 7 6 5 4 3 2 A");
+/// assert_eq!(RankProfile::Inverted.index::<French>(), "2 3 4 5 6 7 8 9 T J Q K A");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RankProfile {
+    /// The deck's own order, where the ace is only high.
+    #[default]
+    AceHigh,
+    /// The ace is below the lowest rank, as in Razz.
+    AceLow,
+    /// The ace is both above the highest rank and below the lowest, so that both `A K Q` and
+    /// `3 2 A` are runs. Runs don't wrap around the corner, so `K A 2` isn't.
+    AceBoth,
+    /// The deck's order turned upside down, so that the deuce is the highest rank and the ace
+    /// the lowest. This isn't deuce to seven lowball, where the ace stays high and is the worst
+    /// low card; that's [`RankProfile::AceHigh`] with the lowest hand winning.
+    Inverted,
+}
+
+impl RankProfile {
+    /// Returns the rank names for the deck in the profile's order, from highest to lowest. With
+    /// [`RankProfile::AceBoth`], the ace is at both ends.
+    #[must_use]
+    pub fn sequence<RankType: Ranked>(&self) -> Vec<&'static str> {
+        let mut names = RankType::run_names();
+        if names.is_empty() {
+            return names;
+        }
+        match self {
+            RankProfile::AceHigh => {}
+            RankProfile::AceLow => names.rotate_left(1),
+            RankProfile::AceBoth => names.push(names[0]),
+            RankProfile::Inverted => names.reverse(),
+        }
+        names
+    }
+
+    /// Returns the index chars of the profile's sequence of ranks, joined with spaces.
+    #[must_use]
+    pub fn index<RankType: Ranked>(&self) -> String {
+        self.sequence::<RankType>()
+            .iter()
+            .map(|name| FluentName::new(name).index_default())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Returns the weight of the rank under the profile, with the lowest rank being `0`, or
+    /// `None` if the rank isn't part of the deck's runs, such as a joker.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let ace = Rank::<French>::from('A');
+    ///
+    /// assert_eq!(RankProfile::AceHigh.weight(&ace), Some(12));
+    /// assert_eq!(RankProfile::AceLow.weight(&ace), Some(0));
+    /// assert_eq!(RankProfile::AceBoth.weight(&ace), Some(13));
+    /// assert_eq!(RankProfile::AceHigh.weight(&Rank::<Modern>::from('B')), None);
+    /// ```
+    #[must_use]
+    pub fn weight<RankType: Ranked>(&self, rank: &Rank<RankType>) -> Option<u32> {
+        let sequence = self.sequence::<RankType>();
+        let position = *Self::positions(&sequence, rank).first()?;
+        u32::try_from(sequence.len() - 1 - position).ok()
+    }
+
+    /// Returns the next rank higher than the passed in rank, if there is one.
+    #[must_use]
+    pub fn next<RankType: Ranked>(&self, rank: &Rank<RankType>) -> Option<Rank<RankType>> {
+        let sequence = self.sequence::<RankType>();
+        Self::positions(&sequence, rank)
+            .into_iter()
+            .find(|&position| position > 0)
+            .map(|position| Rank::new(sequence[position - 1]))
+    }
+
+    /// Returns the next rank lower than the passed in rank, if there is one.
+    #[must_use]
+    pub fn prev<RankType: Ranked>(&self, rank: &Rank<RankType>) -> Option<Rank<RankType>> {
+        let sequence = self.sequence::<RankType>();
+        Self::positions(&sequence, rank)
+            .into_iter()
+            .find(|&position| position + 1 < sequence.len())
+            .map(|position| Rank::new(sequence[position + 1]))
+    }
+
+    /// Splits the passed in ranks into runs of two or more ranks that are next to each other
+    /// in the profile's sequence, each from highest to lowest. Duplicate ranks are ignored.
+    ///
+    /// With [`RankProfile::AceBoth`], an ace is only ever put in one run. If it could be at the
+    /// top of one run and the bottom of another, it goes to the longer of the two, or to the
+    /// top one if they're the same length.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let ranks: Vec<Rank<French>> = "AK32".chars().map(Rank::from).collect();
+    /// let runs = RankProfile::AceBoth.runs(&ranks);
+    ///
+    /// // The ace goes with the longer run, leaving the king on its own:
+    /// assert_eq!(runs, vec![vec![French::THREE, French::TWO, French::ACE]]);
+    /// ```
+    #[must_use]
+    pub fn runs<RankType: Ranked>(&self, ranks: &[Rank<RankType>]) -> Vec<Vec<&'static str>> {
+        let sequence = self.sequence::<RankType>();
+        let present: Vec<bool> = sequence
+            .iter()
+            .map(|name| {
+                ranks
+                    .iter()
+                    .any(|rank| rank.name.fluent_name_string() == *name)
+            })
+            .collect();
+
+        let mut runs: Vec<Vec<&'static str>> = Vec::new();
+        let mut run: Vec<&'static str> = Vec::new();
+        for (name, is_present) in sequence.iter().zip(present) {
+            if is_present {
+                run.push(name);
+            } else {
+                runs.push(std::mem::take(&mut run));
+            }
+        }
+        runs.push(run);
+
+        // With the ace at both ends, it can end up at the top of the first run and the bottom of
+        // the last one, or twice in a run of every rank.
+        if *self == RankProfile::AceBoth && runs.len() > 1 {
+            let last = runs.len() - 1;
+            if !runs[0].is_empty() && !runs[last].is_empty() {
+                if runs[last].len() > runs[0].len() {
+                    runs[0].remove(0);
+                } else {
+                    runs[last].pop();
+                }
+            }
+        } else if let Some(run) = runs.first_mut() {
+            if run.len() == sequence.len() && *self == RankProfile::AceBoth {
+                run.pop();
+            }
+        }
+
+        runs.into_iter().filter(|run| run.len() > 1).collect()
+    }
+
+    /// Where the rank is in the sequence. With [`RankProfile::AceBoth`], the ace is in two places.
+    fn positions<RankType: Ranked>(sequence: &[&'static str], rank: &Rank<RankType>) -> Vec<usize> {
+        sequence
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| rank.name.fluent_name_string() == **name)
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+/// Sorts cards by suit, and then by their rank under the profile, highest first. Ranks that
+/// aren't part of the profile, such as jokers, fall back on their own weight.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let pile = French::from_str("2S AS KS 2H AH").unwrap();
+///
+/// assert_eq!(pile.sort_by_ordering(&RankProfile::AceLow).to_string(), "K♠ 2♠ A♠ 2♥ A♥");
+/// ```
+impl<RankType: Ranked + Clone + Ord, SuitType: Suited + Clone + Ord>
+    CardOrdering<RankType, SuitType> for RankProfile
+{
+    fn compare(&self, a: &Card<RankType, SuitType>, b: &Card<RankType, SuitType>) -> Ordering {
+        let weight =
+            |card: &Card<RankType, SuitType>| self.weight(&card.rank).unwrap_or(card.rank.weight);
+        b.suit
+            .cmp(&a.suit)
+            .then_with(|| weight(b).cmp(&weight(a)))
+            .then_with(|| b.cmp(a))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__rank_profile__tests {
+    use super::*;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::decks::pinochle::Pinochle;
+    use rstest::rstest;
+
+    fn ranks(index: &str) -> Vec<Rank<French>> {
+        index.chars().map(Rank::<French>::from).collect()
+    }
+
+    #[test]
+    fn sequence() {
+        assert_eq!(RankProfile::AceHigh.sequence::<French>().len(), 13);
+        assert_eq!(RankProfile::AceBoth.sequence::<French>().len(), 14);
+        assert_eq!(
+            RankProfile::AceLow.sequence::<Pinochle>().last(),
+            Some(&Pinochle::ACE)
+        );
+    }
+
+    #[test]
+    fn sequence__no_jokers() {
+        assert_eq!(
+            RankProfile::AceHigh.sequence::<Modern>(),
+            RankProfile::AceHigh.sequence::<French>()
+        );
+    }
+
+    #[rstest]
+    #[case(RankProfile::AceHigh, 'A', None, Some('K'))]
+    #[case(RankProfile::AceHigh, '2', Some('3'), None)]
+    #[case(RankProfile::AceLow, 'A', Some('2'), None)]
+    #[case(RankProfile::AceLow, 'K', None, Some('Q'))]
+    #[case(RankProfile::AceBoth, 'A', Some('2'), Some('K'))]
+    #[case(RankProfile::AceBoth, 'K', Some('A'), Some('Q'))]
+    #[case(RankProfile::AceBoth, '2', Some('3'), Some('A'))]
+    #[case(RankProfile::Inverted, '2', None, Some('3'))]
+    #[case(RankProfile::Inverted, 'A', Some('K'), None)]
+    fn next_prev(
+        #[case] profile: RankProfile,
+        #[case] rank: char,
+        #[case] next: Option<char>,
+        #[case] prev: Option<char>,
+    ) {
+        let rank = Rank::<French>::from(rank);
+
+        assert_eq!(profile.next(&rank), next.map(Rank::from));
+        assert_eq!(profile.prev(&rank), prev.map(Rank::from));
+    }
+
+    #[test]
+    fn runs() {
+        let ranks = ranks("AKQ532");
+
+        assert_eq!(
+            RankProfile::AceHigh.runs(&ranks),
+            vec![
+                vec![French::ACE, French::KING, French::QUEEN],
+                vec![French::THREE, French::TWO]
+            ]
+        );
+        assert_eq!(
+            RankProfile::AceLow.runs(&ranks),
+            vec![
+                vec![French::KING, French::QUEEN],
+                vec![French::THREE, French::TWO, French::ACE]
+            ]
+        );
+        // The ace only goes in one run, the top one when they're the same length:
+        assert_eq!(
+            RankProfile::AceBoth.runs(&ranks),
+            vec![
+                vec![French::ACE, French::KING, French::QUEEN],
+                vec![French::THREE, French::TWO]
+            ]
+        );
+        assert_eq!(
+            RankProfile::AceBoth.runs(&self::ranks("AK5432")),
+            vec![vec![
+                French::FIVE,
+                French::FOUR,
+                French::THREE,
+                French::TWO,
+                French::ACE
+            ]]
+        );
+    }
+
+    #[test]
+    fn runs__ace_both_everything() {
+        let ranks = ranks("AKQJT98765432");
+
+        let runs = RankProfile::AceBoth.runs(&ranks);
+
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].len(), 13);
+    }
+
+    #[test]
+    fn runs__no_wrap_around_the_corner() {
+        assert!(RankProfile::AceBoth.runs(&ranks("K2")).is_empty());
+        assert_eq!(
+            RankProfile::AceBoth.runs(&ranks("KA2")),
+            vec![vec![French::ACE, French::KING]]
+        );
+    }
+
+    #[test]
+    fn card_ordering__inverted() {
+        let pile = French::from_str("AS 2S TS").unwrap();
+
+        assert_eq!(
+            pile.sort_by_ordering(&RankProfile::Inverted).to_string(),
+            "2♠ T♠ A♠"
+        );
+    }
+}
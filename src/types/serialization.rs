@@ -0,0 +1,322 @@
+//! [Serde](https://serde.rs/) support for the core types, enabled with the `serde` feature.
+//!
+//! Everything is serialized as the same index strings that the rest of the library uses:
+//!
+//! - A [`Card`] is its index, such as `"AS"` for the ace of spades.
+//! - A [`Rank`] or [`Suit`] is its index character, such as `"A"` or `"S"`.
+//! - A [`Pile`] is an array of card indexes, such as `["AS", "KS"]`.
+//! - A [`FluentName`] is its name, such as `"spades"`.
+//!
+//! Deserialization validates the values against the deck's [`Ranked`] and [`Suited`] types,
+//! so a blank or unknown card is an error instead of a quiet `__`. In human readable formats,
+//! such as JSON, a `Pile` can also be deserialized from a single index string, such as
+//! `"AS KS"`. Binary formats, such as bincode or postcard, that can't describe what they hold,
+//! only take the array.
+//!
+//! A `Pile` on its own doesn't know which deck it's from, so it can't tell if a card is in the
+//! deck or if there are too many copies of it. Use
+//! [`Decked::deserialize_pile()`](crate::types::traits::Decked::deserialize_pile), such as with
+//! `#[serde(deserialize_with = "Spades::deserialize_pile")]`, to also run it through
+//! [`Decked::validate()`](crate::types::traits::Decked::validate):
+//!
+//! ```
+//! use cardpack::prelude::*;
+//! use cardpack::decks::spades::Spades;
+//!
+//! let mut json = serde_json::Deserializer::from_str(r#"["AS","3C"]"#);
+//! assert!(Spades::deserialize_pile(&mut json).is_ok());
+//!
+//! // There's no deuce of clubs in a Spades deck:
+//! let mut json = serde_json::Deserializer::from_str(r#"["AS","2C"]"#);
+//! assert!(Spades::deserialize_pile(&mut json).is_err());
+//!
+//! // Or more than one ace of spades:
+//! let mut json = serde_json::Deserializer::from_str(r#"["AS","AS"]"#);
+//! assert!(Spades::deserialize_pile(&mut json).is_err());
+//! ```
+//!
+//! ```
+//! use cardpack::prelude::*;
+//!
+//! let hand = cards!("AS KS").unwrap();
+//! let json = serde_json::to_string(&hand).unwrap();
+//!
+//! assert_eq!(json, r#"["AS","KS"]"#);
+//! assert_eq!(serde_json::from_str::<FrenchDeck>(&json).unwrap(), hand);
+//! assert_eq!(serde_json::from_str::<FrenchDeck>(r#""AS KS""#).unwrap(), hand);
+//! assert!(serde_json::from_str::<FrenchDeck>(r#"["AS","XX"]"#).is_err());
+//! ```
+use crate::localization::FluentName;
+use crate::localization::Named;
+use crate::types::card::Card;
+use crate::types::card_error::CardError;
+use crate::types::pile::Pile;
+use crate::types::rank::Rank;
+use crate::types::suit::Suit;
+use crate::types::traits::{Ranked, Suited};
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Returns the single char in the string, if that's all it is.
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl<RankType: Ranked + Clone, SuitType: Suited + Clone> Serialize for Card<RankType, SuitType> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.index)
+    }
+}
+
+impl<'de, RankType: Ranked + Clone, SuitType: Suited + Clone> Deserialize<'de>
+    for Card<RankType, SuitType>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match Card::<RankType, SuitType>::from_str(&s) {
+            Ok(card) if !card.is_blank() => Ok(card),
+            _ => Err(D::Error::custom(CardError::InvalidIndex(s))),
+        }
+    }
+}
+
+impl<RankType: Ranked> Serialize for Rank<RankType> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, RankType: Ranked> Deserialize<'de> for Rank<RankType> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match single_char(&s).map(Rank::<RankType>::from) {
+            Some(rank) if !rank.is_blank() => Ok(rank),
+            _ => Err(D::Error::custom(CardError::InvalidFluentRank(s))),
+        }
+    }
+}
+
+impl<SuitType: Suited> Serialize for Suit<SuitType> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.index())
+    }
+}
+
+impl<'de, SuitType: Suited> Deserialize<'de> for Suit<SuitType> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match single_char(&s).map(Suit::<SuitType>::from) {
+            Some(suit) if !suit.is_blank() => Ok(suit),
+            _ => Err(D::Error::custom(CardError::InvalidIndex(s))),
+        }
+    }
+}
+
+impl<
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > Serialize for Pile<RankType, SuitType>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.v())
+    }
+}
+
+impl<
+        'de,
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > Deserialize<'de> for Pile<RankType, SuitType>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PileVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_seq(PileVisitor(PhantomData))
+        }
+    }
+}
+
+/// Accepts either an array of card indexes or a single whitespace separated index string.
+/// Only human readable formats are asked for the string.
+struct PileVisitor<RankType, SuitType>(PhantomData<(RankType, SuitType)>);
+
+impl<
+        'de,
+        RankType: Ranked + Ord + Clone + Default + Hash,
+        SuitType: Suited + Ord + Clone + Default + Hash,
+    > Visitor<'de> for PileVisitor<RankType, SuitType>
+{
+    type Value = Pile<RankType, SuitType>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of card indexes or an index string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        if v.trim().is_empty() {
+            return Ok(Pile::default());
+        }
+        Pile::<RankType, SuitType>::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut pile = Pile::<RankType, SuitType>::default();
+        while let Some(card) = seq.next_element::<Card<RankType, SuitType>>()? {
+            pile.push(card);
+        }
+        Ok(pile)
+    }
+}
+
+impl Serialize for FluentName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.fluent_name_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FluentName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        FluentName::from_str(&s).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__serialization__tests {
+    use super::*;
+    use crate::decks::french::French;
+    use crate::decks::pinochle::Pinochle;
+    use crate::decks::skat::Skat;
+    use crate::types::traits::Decked;
+    use bincode::Options;
+
+    #[test]
+    fn card() {
+        let card = Card::<French, French>::from_str("A♠").unwrap();
+
+        assert_eq!(serde_json::to_string(&card).unwrap(), r#""AS""#);
+        assert_eq!(
+            serde_json::from_str::<Card<French, French>>(r#""AS""#).unwrap(),
+            card
+        );
+    }
+
+    #[test]
+    fn card__invalid() {
+        assert!(serde_json::from_str::<Card<French, French>>(r#""__""#).is_err());
+        assert!(serde_json::from_str::<Card<French, French>>(r#""AX""#).is_err());
+        assert!(serde_json::from_str::<Card<French, French>>(r#""ASS""#).is_err());
+        // There's no daus in a French deck:
+        assert!(serde_json::from_str::<Card<French, French>>(r#""DE""#).is_err());
+        assert!(serde_json::from_str::<Card<Skat, Skat>>(r#""DE""#).is_ok());
+    }
+
+    #[test]
+    fn rank() {
+        let rank = Rank::<French>::new(French::TEN);
+
+        assert_eq!(serde_json::to_string(&rank).unwrap(), r#""T""#);
+        assert_eq!(
+            serde_json::from_str::<Rank<French>>(r#""T""#).unwrap(),
+            rank
+        );
+        assert!(serde_json::from_str::<Rank<French>>(r#""X""#).is_err());
+        assert!(serde_json::from_str::<Rank<French>>(r#""TT""#).is_err());
+    }
+
+    #[test]
+    fn suit() {
+        let suit = Suit::<Skat>::new(Skat::EICHEL);
+
+        assert_eq!(serde_json::to_string(&suit).unwrap(), r#""E""#);
+        assert_eq!(serde_json::from_str::<Suit<Skat>>(r#""E""#).unwrap(), suit);
+        assert!(serde_json::from_str::<Suit<Skat>>(r#""C""#).is_err());
+    }
+
+    #[test]
+    fn pile() {
+        let deck = Pinochle::deck();
+
+        let json = serde_json::to_string(&deck).unwrap();
+        let parsed: Pile<Pinochle, French> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, deck);
+    }
+
+    #[test]
+    fn pile__binary() {
+        let deck = Pinochle::deck().shuffle_seeded(5);
+
+        let bytes = bincode::serialize(&deck).unwrap();
+        let parsed: Pile<Pinochle, French> = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(parsed, deck);
+    }
+
+    #[test]
+    fn pile__binary_invalid() {
+        let bytes = bincode::serialize(&vec!["AS", "XX"]).unwrap();
+
+        assert!(bincode::deserialize::<Pile<French, French>>(&bytes).is_err());
+    }
+
+    #[test]
+    fn deserialize_pile() {
+        let mut json = serde_json::Deserializer::from_str(r#"["AS","AS","TS"]"#);
+        assert!(Pinochle::deserialize_pile(&mut json).is_ok());
+
+        let mut json = serde_json::Deserializer::from_str(r#"["AS","AS","AS"]"#);
+        assert!(Pinochle::deserialize_pile(&mut json).is_err());
+
+        let bytes = bincode::DefaultOptions::new()
+            .serialize(&vec!["AS", "2S"])
+            .unwrap();
+        let mut binary = bincode::Deserializer::from_slice(&bytes, bincode::DefaultOptions::new());
+        assert!(Pinochle::deserialize_pile(&mut binary).is_err());
+    }
+
+    #[test]
+    fn pile__index_string() {
+        let pile: Pile<French, French> = serde_json::from_str(r#""AS KS QS""#).unwrap();
+
+        assert_eq!(pile.to_string(), "A♠ K♠ Q♠");
+        assert!(serde_json::from_str::<Pile<French, French>>(r#""AS XX""#).is_err());
+    }
+
+    #[test]
+    fn pile__empty() {
+        let pile = Pile::<French, French>::default();
+
+        assert_eq!(serde_json::to_string(&pile).unwrap(), "[]");
+        assert_eq!(
+            serde_json::from_str::<Pile<French, French>>("[]").unwrap(),
+            pile
+        );
+        assert_eq!(
+            serde_json::from_str::<Pile<French, French>>(r#""""#).unwrap(),
+            pile
+        );
+    }
+
+    #[test]
+    fn fluent_name() {
+        let name = FluentName::new(French::SPADES);
+
+        assert_eq!(serde_json::to_string(&name).unwrap(), r#""spades""#);
+        assert_eq!(
+            serde_json::from_str::<FluentName>(r#""spades""#).unwrap(),
+            name
+        );
+        assert!(serde_json::from_str::<FluentName>(r#""not valid!""#).is_err());
+    }
+}
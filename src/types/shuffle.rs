@@ -0,0 +1,342 @@
+use crate::types::utils::Random;
+use rand::RngCore;
+
+/// Models of the ways that people physically shuffle a deck of cards. Unlike a Fisher–Yates
+/// shuffle, which produces a perfectly uniform permutation, these let simulations model how
+/// random a deal really is after, say, one riffle versus seven.
+///
+/// Each model works on a `Vec`, with the first element being the top of the deck. Every model
+/// that needs randomness takes it from the passed in RNG, so a
+/// [`SeededRng`](crate::types::utils::SeededRng) makes them reproducible.
+///
+/// Most of the time these are used through the
+/// [`Shufflable`](crate::types::traits::Shufflable) trait, which [`Pile`](crate::types::pile::Pile)
+/// implements.
+pub struct Shuffle;
+
+impl Shuffle {
+    /// The chance, out of [`Shuffle::OVERHAND_BREAK_ODDS`], that an overhand shuffle will break
+    /// between any two cards. One in four gives an average packet of four cards.
+    pub const OVERHAND_BREAK_ODDS: usize = 4;
+
+    /// The most packets that a strip shuffle will pull off of the deck.
+    pub const STRIP_MAX_PACKETS: usize = 6;
+
+    /// The [Gilbert–Shannon–Reeds](https://en.wikipedia.org/wiki/Gilbert%E2%80%93Shannon%E2%80%93Reeds_model)
+    /// model of a riffle shuffle.
+    ///
+    /// The deck is cut into two packets, with the size of the top packet following a binomial
+    /// distribution, and then the packets are riffled together, with the next card coming
+    /// from a packet with a probability that is proportional to the packet's remaining size.
+    ///
+    /// Bayer and Diaconis showed that it takes about seven of these to properly mix a 52
+    /// card deck.
+    pub fn riffle<T, R: RngCore + ?Sized>(v: &mut Vec<T>, rng: &mut R) {
+        let cut = Shuffle::binomial_cut(v.len(), rng);
+        let mut bottom = v.split_off(cut);
+        let mut top = std::mem::take(v);
+
+        // Reversed so that the next card to drop is at the end, where `pop()` is cheap.
+        top.reverse();
+        bottom.reverse();
+
+        while !top.is_empty() || !bottom.is_empty() {
+            let remaining = top.len() + bottom.len();
+            if Random::below(rng, remaining) < top.len() {
+                v.extend(top.pop());
+            } else {
+                v.extend(bottom.pop());
+            }
+        }
+    }
+
+    /// A perfect riffle where the deck is split exactly in half, and the cards alternate
+    /// starting with the top half, so that the top card stays on top. Eight of these will
+    /// return a 52 card deck to its original order.
+    ///
+    /// With an odd number of cards, the top half gets the extra card.
+    pub fn faro_out<T>(v: &mut Vec<T>) {
+        let half = v.len().div_ceil(2);
+        Shuffle::interleave(v, half, true);
+    }
+
+    /// A perfect riffle where the deck is split exactly in half, and the cards alternate
+    /// starting with the bottom half, so that the top card becomes the second card.
+    ///
+    /// With an odd number of cards, the bottom half gets the extra card.
+    pub fn faro_in<T>(v: &mut Vec<T>) {
+        let half = v.len() / 2;
+        Shuffle::interleave(v, half, false);
+    }
+
+    /// The overhand shuffle, where small packets are slid off of the top of the deck in one
+    /// hand and dropped on top of each other in the other hand. This reverses the order of the
+    /// packets while keeping the order of the cards within each packet.
+    ///
+    /// This follows [Pemantle's model](https://www.math.upenn.edu/~pemantle/papers/overhand2.pdf),
+    /// where the deck breaks between any two cards with a fixed probability, in this case one in
+    /// [`Shuffle::OVERHAND_BREAK_ODDS`]. It is a notoriously bad way to mix a deck.
+    pub fn overhand<T, R: RngCore + ?Sized>(v: &mut Vec<T>, rng: &mut R) {
+        let mut packets: Vec<Vec<T>> = Vec::new();
+        let mut packet: Vec<T> = Vec::new();
+
+        for card in v.drain(..) {
+            if !packet.is_empty() && Random::below(rng, Shuffle::OVERHAND_BREAK_ODDS) == 0 {
+                packets.push(std::mem::take(&mut packet));
+            }
+            packet.push(card);
+        }
+        packets.push(packet);
+
+        Shuffle::restack(v, packets);
+    }
+
+    /// The strip shuffle, where a few large packets are pulled off of the top of the deck
+    /// on the table and stacked on top of each other. Like the overhand shuffle, this reverses
+    /// the order of the packets, but there are far fewer of them, between two and
+    /// [`Shuffle::STRIP_MAX_PACKETS`].
+    pub fn strip<T, R: RngCore + ?Sized>(v: &mut Vec<T>, rng: &mut R) {
+        if v.len() < 2 {
+            return;
+        }
+        let count = (2 + Random::below(rng, Shuffle::STRIP_MAX_PACKETS - 1)).min(v.len());
+
+        // Pick `count - 1` distinct places to break the deck.
+        let mut breaks: Vec<usize> = (1..v.len()).collect();
+        Random::fisher_yates(&mut breaks, rng);
+        breaks.truncate(count - 1);
+        breaks.sort_unstable();
+
+        let mut packets: Vec<Vec<T>> = Vec::new();
+        for at in breaks.into_iter().rev() {
+            packets.push(v.split_off(at));
+        }
+        packets.push(std::mem::take(v));
+        packets.reverse();
+
+        Shuffle::restack(v, packets);
+    }
+
+    /// The pile shuffle, where the cards are dealt out one at a time into the passed in number of
+    /// piles, which are then picked up in a random order. On its own this is not very random,
+    /// but it is a common way to check the count of a deck.
+    pub fn pile<T, R: RngCore + ?Sized>(v: &mut Vec<T>, piles: usize, rng: &mut R) {
+        if piles < 2 {
+            return;
+        }
+        let mut stacks: Vec<Vec<T>> = (0..piles).map(|_| Vec::new()).collect();
+        for (i, card) in v.drain(..).enumerate() {
+            // Each card is dealt on top of the pile.
+            stacks[i % piles].insert(0, card);
+        }
+        Random::fisher_yates(&mut stacks, rng);
+
+        v.extend(stacks.into_iter().flatten());
+    }
+
+    /// Cuts the deck, moving the top packet to the bottom. Like the riffle, where the deck is cut
+    /// follows a binomial distribution, so it will usually be near the middle.
+    pub fn cut<T, R: RngCore + ?Sized>(v: &mut [T], rng: &mut R) {
+        let at = Shuffle::binomial_cut(v.len(), rng);
+        v.rotate_left(at);
+    }
+
+    /// The number of heads from flipping a coin once for every card.
+    fn binomial_cut<R: RngCore + ?Sized>(n: usize, rng: &mut R) -> usize {
+        (0..n).filter(|_| Random::below(rng, 2) == 1).count()
+    }
+
+    /// Alternates the cards from the two halves of the deck, split at `half`.
+    fn interleave<T>(v: &mut Vec<T>, half: usize, top_first: bool) {
+        let bottom = v.split_off(half);
+        let top = std::mem::take(v);
+
+        let (mut first, mut second) = if top_first {
+            (top.into_iter(), bottom.into_iter())
+        } else {
+            (bottom.into_iter(), top.into_iter())
+        };
+        loop {
+            match (first.next(), second.next()) {
+                (None, None) => break,
+                (a, b) => {
+                    v.extend(a);
+                    v.extend(b);
+                }
+            }
+        }
+    }
+
+    /// Stacks the packets, taken from the top of the deck in order, so that the last packet taken
+    /// ends up on top.
+    fn restack<T>(v: &mut Vec<T>, packets: Vec<Vec<T>>) {
+        v.extend(packets.into_iter().rev().flatten());
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod types__shuffle__tests {
+    use super::*;
+
+    fn deck() -> Vec<usize> {
+        (0..52).collect()
+    }
+
+    fn is_permutation(v: &[usize]) -> bool {
+        let mut sorted = v.to_vec();
+        sorted.sort_unstable();
+        sorted == deck()
+    }
+
+    /// The number of rising sequences, which is one more than the number of cards that come
+    /// before the card that was originally above them.
+    fn rising_sequences(v: &[usize]) -> usize {
+        let mut positions = vec![0; v.len()];
+        for (position, &card) in v.iter().enumerate() {
+            positions[card] = position;
+        }
+        1 + positions.windows(2).filter(|w| w[1] < w[0]).count()
+    }
+
+    /// The first packet taken off of the top ends up on the bottom.
+    fn is_top_packet_on_bottom(v: &[usize]) -> bool {
+        let at = v.iter().position(|&c| c == 0).unwrap();
+        at > 0 && v[at..] == deck()[..v.len() - at]
+    }
+
+    #[test]
+    fn riffle() {
+        let mut v = deck();
+
+        Shuffle::riffle(&mut v, &mut Random::seeded(1));
+
+        assert!(is_permutation(&v));
+        assert_ne!(v, deck());
+    }
+
+    /// After a single riffle the deck is made up of two interleaved rising sequences.
+    #[test]
+    fn riffle__rising_sequences() {
+        let mut v = deck();
+
+        Shuffle::riffle(&mut v, &mut Random::seeded(2));
+
+        assert_eq!(rising_sequences(&v), 2);
+    }
+
+    #[test]
+    fn riffle__seven() {
+        let mut one = deck();
+        let mut seven = deck();
+        let mut rng = Random::seeded(3);
+
+        Shuffle::riffle(&mut one, &mut rng);
+        for _ in 0..7 {
+            Shuffle::riffle(&mut seven, &mut rng);
+        }
+
+        assert!(is_permutation(&seven));
+        assert!(rising_sequences(&seven) > rising_sequences(&one));
+    }
+
+    #[test]
+    fn faro_out() {
+        let mut v: Vec<usize> = (0..6).collect();
+
+        Shuffle::faro_out(&mut v);
+
+        assert_eq!(v, vec![0, 3, 1, 4, 2, 5]);
+    }
+
+    #[test]
+    fn faro_out__eight_restores_52() {
+        let mut v = deck();
+
+        for i in 1..=8 {
+            Shuffle::faro_out(&mut v);
+            assert_eq!(v == deck(), i == 8);
+        }
+    }
+
+    #[test]
+    fn faro_out__odd() {
+        let mut v: Vec<usize> = (0..5).collect();
+
+        Shuffle::faro_out(&mut v);
+
+        assert_eq!(v, vec![0, 3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn faro_in() {
+        let mut v: Vec<usize> = (0..6).collect();
+
+        Shuffle::faro_in(&mut v);
+
+        assert_eq!(v, vec![3, 0, 4, 1, 5, 2]);
+    }
+
+    #[test]
+    fn faro_in__odd() {
+        let mut v: Vec<usize> = (0..5).collect();
+
+        Shuffle::faro_in(&mut v);
+
+        assert_eq!(v, vec![2, 0, 3, 1, 4]);
+    }
+
+    #[test]
+    fn overhand() {
+        let mut v = deck();
+
+        Shuffle::overhand(&mut v, &mut Random::seeded(4));
+
+        assert!(is_permutation(&v));
+        assert!(is_top_packet_on_bottom(&v));
+    }
+
+    #[test]
+    fn strip() {
+        let mut v = deck();
+
+        Shuffle::strip(&mut v, &mut Random::seeded(5));
+
+        assert!(is_permutation(&v));
+        assert!(is_top_packet_on_bottom(&v));
+        assert!(rising_sequences(&v) <= Shuffle::STRIP_MAX_PACKETS);
+    }
+
+    #[test]
+    fn strip__small() {
+        let mut one = vec![1];
+        let mut two = vec![1, 2];
+
+        Shuffle::strip(&mut one, &mut Random::seeded(6));
+        Shuffle::strip(&mut two, &mut Random::seeded(6));
+
+        assert_eq!(one, vec![1]);
+        assert_eq!(two, vec![2, 1]);
+    }
+
+    #[test]
+    fn pile() {
+        let mut v: Vec<usize> = (0..6).collect();
+
+        Shuffle::pile(&mut v, 3, &mut Random::seeded(7));
+
+        let mut piles: Vec<Vec<usize>> = v.chunks(2).map(<[usize]>::to_vec).collect();
+        piles.sort();
+        assert_eq!(piles, vec![vec![3, 0], vec![4, 1], vec![5, 2]]);
+    }
+
+    #[test]
+    fn cut() {
+        let mut v = deck();
+
+        Shuffle::cut(&mut v, &mut Random::seeded(8));
+
+        assert!(is_top_packet_on_bottom(&v));
+        assert_eq!(rising_sequences(&v), 2);
+    }
+}
@@ -1,7 +1,3 @@
-use crate::decks::french::French;
-use crate::decks::modern::Modern;
-use crate::decks::skat::Skat;
-use crate::decks::tarot::Tarot;
 use crate::localization::{FluentName, Named};
 use crate::types::traits::Suited;
 use crate::types::utils::Bit;
@@ -82,7 +78,7 @@ macro_rules! suit {
 
 /// TODO: Create a five suited deck to test the boundaries.
 /// <https://cards.fandom.com/wiki/Suit_(cards)#Five_Suit_Decks/>
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Suit<SuitType>
 where
     SuitType: Suited,
@@ -107,6 +103,15 @@ where
         }
     }
 
+    /// The `Suit` with the name and weight, without looking the weight up.
+    pub(crate) fn from_parts(name: FluentName, weight: u32) -> Suit<SuitType> {
+        Suit::<SuitType> {
+            weight,
+            name,
+            phantom_data: PhantomData,
+        }
+    }
+
     /// Used to generate the `Card`'s binary signature, aka [Cactus Kev](https://suffe.cool/poker/evaluator.html)
     /// numbers.
     ///
@@ -130,6 +135,30 @@ where
             .collect()
     }
 
+    /// The deck's special suits, such as the jokers of a [`Modern`](crate::decks::modern::Modern)
+    /// deck.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(Suit::<Modern>::special_suits(), vec![Suit::new(Modern::JOKER)]);
+    /// assert!(Suit::<French>::special_suits().is_empty());
+    /// ```
+    #[must_use]
+    pub fn special_suits() -> Vec<Self> {
+        SuitType::special_suit_names()
+            .iter()
+            .map(|name| Self::new(name))
+            .collect()
+    }
+
+    /// Returns `true` if the suit is one of the deck's
+    /// [special suits](Suited::special_suit_names).
+    #[must_use]
+    pub fn is_special(&self) -> bool {
+        SuitType::special_suit_names().contains(&self.fluent_name_string())
+    }
+
     #[must_use]
     pub fn index(&self) -> String {
         self.name.fluent_value(
@@ -187,7 +216,7 @@ where
         &self.name
     }
 
-    fn fluent_name_string(&self) -> &String {
+    fn fluent_name_string(&self) -> &'static str {
         self.name.fluent_name_string()
     }
 
@@ -209,6 +238,14 @@ impl<SuiteType: Suited> Suited for Suit<SuiteType> {
         SuiteType::suit_names()
     }
 
+    fn special_suit_names() -> Vec<&'static str> {
+        SuiteType::special_suit_names()
+    }
+
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        SuiteType::suit_for_char(c)
+    }
+
     fn type_name() -> &'static str {
         SuiteType::type_name()
     }
@@ -223,23 +260,9 @@ impl<SuitType: Suited> From<char> for Suit<SuitType> {
                 phantom_data: PhantomData,
             };
         }
-        match SuitType::type_name() {
-            Skat::DECK_NAME => match c {
-                '♧' | '♣' | 'E' | 'e' => Suit::<SuitType>::new(Skat::EICHEL),
-                '♤' | '♠' | 'L' | 'l' => Suit::<SuitType>::new(Skat::LAUB),
-                '♡' | '♥' | 'H' | 'h' => Suit::<SuitType>::new(Skat::HERZ),
-                '♢' | '♦' | 'S' | 's' => Suit::<SuitType>::new(Skat::SHELLEN),
-                _ => Suit::<SuitType>::new(FluentName::BLANK),
-            },
-            _ => match c {
-                'S' | 's' | '♤' | '♠' => Suit::<SuitType>::new(French::SPADES),
-                'H' | 'h' | '♡' | '♥' => Suit::<SuitType>::new(French::HEARTS),
-                'D' | 'd' | '♢' | '♦' => Suit::<SuitType>::new(French::DIAMONDS),
-                'C' | 'c' | '♧' | '♣' => Suit::<SuitType>::new(French::CLUBS),
-                '🃟' | 'J' | 'j' | 'T' | 't' => Suit::new(Modern::JOKER),
-                'M' | 'm' => Suit::new(Tarot::MAJOR_ARCANA),
-                _ => Suit::new(FluentName::BLANK),
-            },
+        match SuitType::suit_for_char(&c) {
+            Some(name) => Suit::<SuitType>::new(name),
+            None => Suit::new(FluentName::BLANK),
         }
     }
 }
@@ -248,6 +271,10 @@ impl<SuitType: Suited> From<char> for Suit<SuitType> {
 #[allow(non_snake_case)]
 mod types__suit__tests {
     use super::*;
+    use crate::decks::french::French;
+    use crate::decks::modern::Modern;
+    use crate::decks::skat::Skat;
+    use crate::decks::tarot::Tarot;
     use rstest::rstest;
 
     #[test]
@@ -287,7 +314,7 @@ mod types__suit__tests {
         let suit = Suit::<French>::new(French::SPADES);
 
         assert_eq!(suit.symbol(), "♠");
-        assert_eq!(suit.to_string(), suit.symbol())
+        assert_eq!(suit.to_string(), suit.symbol());
     }
 
     #[test]
@@ -295,7 +322,7 @@ mod types__suit__tests {
         let suit = Suit::<French>::from('_');
 
         assert_eq!(suit.symbol(), "_");
-        assert_eq!(suit.to_string(), suit.symbol())
+        assert_eq!(suit.to_string(), suit.symbol());
     }
 
     #[rstest]
@@ -341,4 +368,14 @@ mod types__suit__tests {
         assert_eq!(suits[3].fluent_name_string(), "spades");
         assert_eq!(suits[3].weight, 0);
     }
+
+    #[test]
+    fn from__char__other_decks() {
+        assert_eq!(Suit::<Skat>::from('S'), Suit::new(Skat::SHELLEN));
+        assert_eq!(Suit::<Modern>::from('J'), Suit::new(Modern::JOKER));
+        assert_eq!(Suit::<Tarot>::from('C'), Suit::new(Tarot::CUPS));
+        assert_eq!(Suit::<Tarot>::from('M'), Suit::new(Tarot::MAJOR_ARCANA));
+        assert!(Suit::<French>::from('J').is_blank());
+        assert!(Suit::<French>::from('M').is_blank());
+    }
 }
@@ -1,9 +1,17 @@
 use crate::localization::FluentName;
 use crate::localization::Named;
 use crate::types::card::Card;
+use crate::types::card_error::CardError;
+use crate::types::deck_table::DeckTable;
+use crate::types::diagnostic::Diagnostics;
+use crate::types::encoding::Encoding;
 use crate::types::pile::Pile;
 use crate::types::rank::Rank;
+use crate::types::shuffle::Shuffle;
 use crate::types::suit::Suit;
+use crate::types::utils::Bit;
+use rand::RngCore;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::hash::Hash;
 
@@ -14,8 +22,10 @@ pub trait Decked<
 {
     /// This trait makes me very happy. It feels like it has an elegance that I really love.
     ///
-    /// NOTE: We are going to need to override it for decks that have two tiers of suits, such
-    /// as tarot decks and ones with jokers.
+    /// Decks with two tiers of suits, such as tarot decks with their major arcana and ones with
+    /// jokers, start with every [special rank](Ranked::special_rank_names) in each
+    /// [special suit](Suited::special_suit_names), followed by the regular ranks in each regular
+    /// suit.
     ///
     /// ```rust
     /// use cardpack::decks::french::French;
@@ -34,6 +44,12 @@ pub trait Decked<
 
         let mut pile = Pile::<RankType, SuitType>::from(Vec::new());
 
+        for suit in &Suit::<SuitType>::special_suits() {
+            for rank in &Rank::<RankType>::special_ranks() {
+                pile.push(Card::<RankType, SuitType>::new(rank.clone(), suit.clone()));
+            }
+        }
+
         for suit in &suits {
             for rank in &ranks {
                 pile.push(Card::<RankType, SuitType>::new(rank.clone(), suit.clone()));
@@ -82,6 +98,151 @@ pub trait Decked<
 
     fn guide() -> Option<String>;
 
+    /// Validates that every [`Card`] in the passed in [`Pile`] belongs to the deck, and that
+    /// there aren't more copies of any `Card` than there are in the deck. Cards are compared by
+    /// their index, so a re-weighted card is still the same card.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    /// use cardpack::decks::spades::Spades;
+    ///
+    /// assert!(French::validate(&French::deck()).is_ok());
+    /// assert!(Pinochle::validate(&Pinochle::from_str("AS AS TS").unwrap()).is_ok());
+    ///
+    /// assert_eq!(
+    ///     Pinochle::validate(&Pinochle::from_str("AS AS AS KS").unwrap()).unwrap_err(),
+    ///     CardError::TooManyCopies("AS".to_string())
+    /// );
+    /// assert_eq!(
+    ///     Spades::validate(&Modern::from_str("2S 2C 2D").unwrap()).unwrap_err(),
+    ///     CardError::NotInDeck("2C 2D".to_string())
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `CardError::NotInDeck` with the indexes of the cards that aren't in the deck,
+    /// `CardError::TooManyCopies` with the indexes of the cards that there are too many of, or
+    /// `CardError::NotInDeckAndTooManyCopies` with both if there are both kinds.
+    fn validate(pile: &Pile<RankType, SuitType>) -> Result<(), CardError> {
+        pile.validate_against(&Self::deck())
+    }
+
+    /// Parses the index like [`Pile::diagnose()`], but also reports the cards that aren't in
+    /// the deck and the copies past what it has, and only suggests cards that are in it.
+    ///
+    /// ```rust
+    /// use cardpack::prelude::*;
+    /// use cardpack::decks::spades::Spades;
+    ///
+    /// let diagnostics = Spades::diagnose("AS 2C").unwrap_err();
+    ///
+    /// assert_eq!(diagnostics.v()[0].reason, ParseReason::NotInDeck);
+    /// assert_eq!(diagnostics.v()[0].span, 3..5);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Diagnostics`] with every card that couldn't be parsed, or isn't in the deck.
+    fn diagnose(index: &str) -> Result<Pile<RankType, SuitType>, Diagnostics> {
+        Diagnostics::parse(index, Some(&Self::deck()))
+    }
+
+    /// Deserializes a [`Pile`] and runs it through [`validate()`](Decked::validate), for use
+    /// with `#[serde(deserialize_with = "...")]`. See
+    /// [`serialization`](crate::types::serialization) for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer's error if the `Pile` can't be deserialized, or if any of its
+    /// cards aren't in the deck or there are too many copies of them.
+    #[cfg(feature = "serde")]
+    fn deserialize_pile<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Pile<RankType, SuitType>, D::Error> {
+        use serde::de::Error;
+        use serde::Deserialize;
+
+        let pile = Pile::<RankType, SuitType>::deserialize(deserializer)?;
+        Self::validate(&pile).map_err(D::Error::custom)?;
+        Ok(pile)
+    }
+
+    /// Returns every distinct card in the deck once, in the order that they first show up in
+    /// [`deck()`](Decked::deck). A card's position in this `Pile` is its
+    /// [`ordinal()`](Decked::ordinal).
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(Pinochle::deck().len(), 48);
+    /// assert_eq!(Pinochle::distinct().len(), 24);
+    /// assert_eq!(Tarot::distinct().len(), 78);
+    /// ```
+    #[must_use]
+    fn distinct() -> Pile<RankType, SuitType>
+    where
+        Self: Sized + 'static,
+        RankType: 'static,
+        SuitType: 'static,
+    {
+        let table = DeckTable::of::<Self, RankType, SuitType>();
+        (0..table.len())
+            .filter_map(|ordinal| table.card(ordinal))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Returns the card with the passed in [ordinal](Decked::ordinal), if there is one.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(French::from_ordinal(0), Some(card!(AS)));
+    /// assert_eq!(French::from_ordinal(51), Some(card!(2C)));
+    /// assert_eq!(French::from_ordinal(52), None);
+    /// ```
+    #[must_use]
+    fn from_ordinal(ordinal: usize) -> Option<Card<RankType, SuitType>>
+    where
+        Self: Sized + 'static,
+        RankType: 'static,
+        SuitType: 'static,
+    {
+        DeckTable::of::<Self, RankType, SuitType>().card(ordinal)
+    }
+
+    /// Returns a stable id for the card from `0` up to the number of distinct cards in the deck,
+    /// so that cards can be used to index into arrays: `0..52` for
+    /// [`French`](crate::decks::french::French), `0..78` for
+    /// [`Tarot`](crate::decks::tarot::Tarot), and `0..24` for
+    /// [`Euchre24`](crate::decks::euchre24::Euchre24). Returns `None` if the card isn't in the deck.
+    ///
+    /// The ordinals are worked out from the deck the first time that they're needed, and then
+    /// kept for the rest of the program, so after that this is a single hash lookup.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let mut counts = [0u8; 52];
+    /// for card in cards!("AS KH AS").unwrap().v() {
+    ///     counts[French::ordinal(card).unwrap()] += 1;
+    /// }
+    ///
+    /// assert_eq!(counts[0], 2);
+    /// assert_eq!(counts[French::ordinal(&card!(KH)).unwrap()], 1);
+    /// assert_eq!(Tarot::ordinal(&Tarot::deck().draw_last().unwrap()), Some(77));
+    /// ```
+    #[must_use]
+    fn ordinal(card: &Card<RankType, SuitType>) -> Option<usize>
+    where
+        Self: Sized + 'static,
+        RankType: 'static,
+        SuitType: 'static,
+    {
+        DeckTable::of::<Self, RankType, SuitType>().ordinal(card)
+    }
+
     #[must_use]
     fn name() -> String {
         let full_name = std::any::type_name::<Self>();
@@ -89,6 +250,92 @@ pub trait Decked<
     }
 }
 
+/// A deck-aware binary encoding of the deck's cards into a `u64`, generated from the ranks and suits
+/// in the deck. See [`Encoding`] for the layout.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let card = Tarot::deck().draw_first().unwrap();
+/// let bits = Tarot::encode(&card).unwrap();
+///
+/// assert_eq!(Tarot::decode(bits), Some(card));
+/// assert_eq!(
+///     French::binary_string_guided(French::encode(&card!(KS)).unwrap()),
+///     "xxxxxxxx xxxxxxxx xxxxxxxx xxxxxxxx xxxAKQJT 98765432 ♠♥♦♣rrrr xxpppppp\n\
+///      00000000 00000000 00000000 00000000 00001000 00000000 10001011 00100101"
+/// );
+/// ```
+pub trait Encoded<
+    RankType: Ranked + Clone + Hash + Ord + Default + 'static,
+    SuitType: Suited + Clone + Hash + Ord + Default + 'static,
+>: Decked<RankType, SuitType> + Sized + 'static
+{
+    /// The deck's encoding. This builds the deck, so hold on to it when encoding many cards.
+    #[must_use]
+    fn encoding() -> Encoding {
+        Encoding::new(&Self::distinct())
+    }
+
+    #[must_use]
+    fn encode(card: &Card<RankType, SuitType>) -> Option<u64> {
+        Self::encoding().encode(card)
+    }
+
+    /// Returns the card from the deck that the bits encode, if there is one.
+    #[must_use]
+    fn decode(bits: u64) -> Option<Card<RankType, SuitType>> {
+        let (rank, suit) = Self::encoding().decode(bits)?;
+        Self::distinct().into_iter().find(|card| {
+            card.rank.fluent_name_string() == rank && card.suit.fluent_name_string() == suit
+        })
+    }
+
+    /// The guide to the bits of the deck's encoding.
+    #[must_use]
+    fn binary_guide() -> String {
+        Self::encoding().guide()
+    }
+
+    /// Returns the bits with the deck's guide above them.
+    #[must_use]
+    fn binary_string_guided(bits: u64) -> String {
+        format!("{}\n{}", Self::binary_guide(), Bit::string64(bits))
+    }
+}
+
+/// Ties a deck to the `Rank` and `Suit` types of its cards, along with the chars that their
+/// indexes are written with, as constants. This is what lets the [`card!`](crate::card) and
+/// [`pile!`](crate::pile) macros check an index while it's being compiled.
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// assert!(Literals::is_pile("DE KH", Skat::RANK_CHARS, Skat::SUIT_CHARS));
+/// assert!(!Literals::is_pile("DE LH", Skat::RANK_CHARS, Skat::SUIT_CHARS));
+/// assert_eq!(pile!(Skat: "DE KH"), Skat::from_str("DE KH").unwrap());
+/// ```
+pub trait Literal {
+    type RankType: Ranked + Clone + Hash + Ord + Default;
+    type SuitType: Suited + Clone + Hash + Ord + Default;
+
+    /// The chars that the deck's ranks can be written with.
+    const RANK_CHARS: &'static [char];
+
+    /// The chars that the deck's suits can be written with.
+    const SUIT_CHARS: &'static [char];
+
+    /// The chars in [`RANK_CHARS`](Literal::RANK_CHARS) of the deck's
+    /// [special ranks](Ranked::special_rank_names), which only go with the
+    /// [special suits](Literal::SPECIAL_SUIT_CHARS). Defaults to none.
+    const SPECIAL_RANK_CHARS: &'static [char] = &[];
+
+    /// The chars in [`SUIT_CHARS`](Literal::SUIT_CHARS) of the deck's
+    /// [special suits](Suited::special_suit_names), which only go with the
+    /// [special ranks](Literal::SPECIAL_RANK_CHARS). Defaults to none.
+    const SPECIAL_SUIT_CHARS: &'static [char] = &[];
+}
+
 pub trait Ranked {
     #[must_use]
     fn is_valid_rank_char(c: &char) -> bool {
@@ -103,9 +350,75 @@ pub trait Ranked {
     /// called by the [`Decked`] trait's `deck()`.
     fn rank_names() -> Vec<&'static str>;
 
-    /// Used for match statements in the `Rank` struct.
+    /// [`FluentName`] vector of the ranks that only show up in the deck's
+    /// [special suits](Suited::special_suit_names), such as the major arcana of a tarot deck or
+    /// the jokers of a [`Modern`](crate::decks::modern::Modern) deck, from highest to lowest.
+    /// Defaults to none.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
     ///
-    /// This feels very hacky. It would be great if we could push this down to the deck implementations.
+    /// assert_eq!(Modern::special_rank_names(), vec![Modern::BIG, Modern::LITTLE]);
+    /// assert!(French::special_rank_names().is_empty());
+    /// ```
+    #[must_use]
+    fn special_rank_names() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// The weight of the named rank, which is what cards are sorted by. Defaults to the weight
+    /// from the fluent templates. Decks whose ranks are ordered differently than the fluent
+    /// templates have them, such as a tarot deck's low aces, should override it.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(French::rank_weight(French::ACE), 12);
+    /// assert_eq!(Tarot::rank_weight(French::ACE), 0);
+    /// ```
+    #[must_use]
+    fn rank_weight(name: &str) -> u32 {
+        FluentName::new(name).weight()
+    }
+
+    /// The names of the ranks that make up runs, such as straights in poker, from highest to
+    /// lowest. A [`RankProfile`](crate::types::rank_profile::RankProfile) treats the first one as
+    /// the ace.
+    ///
+    /// Defaults to [`rank_names()`](Ranked::rank_names). Decks with ranks that aren't part of
+    /// runs, such as jokers, should leave them out.
+    #[must_use]
+    fn run_names() -> Vec<&'static str> {
+        Self::rank_names()
+    }
+
+    /// Returns the [`FluentName`] of the rank that the char stands for, which is how
+    /// [`Rank::from()`](Rank) parses a rank index char. Only chars that pass
+    /// [`is_valid_rank_char()`](Ranked::is_valid_rank_char) are ever passed in.
+    ///
+    /// By default, it looks for the rank in [`rank_names()`](Ranked::rank_names), and then in
+    /// [`special_rank_names()`](Ranked::special_rank_names), whose index,
+    /// from the fluent templates, matches the char, ignoring case. Decks that have other chars
+    /// for their ranks, such as `0` for a ten, should override it.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert_eq!(French::rank_for_char(&'q'), Some(French::QUEEN));
+    /// assert_eq!(French::rank_for_char(&'0'), Some(French::TEN));
+    /// assert_eq!(Skat::rank_for_char(&'O'), Some(Skat::OBER));
+    /// assert_eq!(French::rank_for_char(&'O'), None);
+    /// ```
+    #[must_use]
+    fn rank_for_char(c: &char) -> Option<&'static str> {
+        let index = c.to_uppercase().to_string();
+        Self::rank_names()
+            .into_iter()
+            .chain(Self::special_rank_names())
+            .find(|name| FluentName::new(name).index_default() == index)
+    }
+
+    /// The name of the type of deck that the ranks are from.
     fn type_name() -> &'static str;
 }
 
@@ -123,17 +436,252 @@ pub trait Suited {
 
     fn suit_names() -> Vec<&'static str>;
 
-    /// Used for match statements in the `Suit` struct.
+    /// The suits that stand apart from the regular [`suit_names()`](Suited::suit_names), such
+    /// as the major arcana of a tarot deck or the jokers of a
+    /// [`Modern`](crate::decks::modern::Modern) deck, from highest to lowest. They're made up of
+    /// the [special ranks](Ranked::special_rank_names) instead of the regular ones. Defaults to
+    /// none.
+    #[must_use]
+    fn special_suit_names() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Returns the [`FluentName`] of the suit that the char stands for, which is how
+    /// [`Suit::from()`](Suit) parses a suit index char. Only chars that pass
+    /// [`is_valid_suit_char()`](Suited::is_valid_suit_char) are ever passed in.
+    ///
+    /// By default, it looks for the suit in [`suit_names()`](Suited::suit_names), and then in
+    /// [`special_suit_names()`](Suited::special_suit_names), whose index
+    /// or symbol, from the fluent templates, matches the char, ignoring case. Decks that have
+    /// other chars for their suits, such as `♤` for spades, should override it.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
     ///
-    /// This feels very hacky. It would be great if we could push this down to the deck implementations.
+    /// assert_eq!(French::suit_for_char(&'♤'), Some(French::SPADES));
+    /// assert_eq!(Skat::suit_for_char(&'S'), Some(Skat::SHELLEN));
+    /// assert_eq!(Tarot::suit_for_char(&'S'), Some(Tarot::SWORDS));
+    /// assert_eq!(French::suit_for_char(&'E'), None);
+    /// ```
+    #[must_use]
+    fn suit_for_char(c: &char) -> Option<&'static str> {
+        let index = c.to_uppercase().to_string();
+        let symbol = c.to_string();
+        Self::suit_names()
+            .into_iter()
+            .chain(Self::special_suit_names())
+            .find(|name| {
+                let name = FluentName::new(name);
+                name.index_default() == index
+                    || name.fluent_value(FluentName::FLUENT_SYMBOL_SECTION, &FluentName::US_ENGLISH)
+                        == symbol
+            })
+    }
+
+    /// The name of the type of deck that the suits are from.
     fn type_name() -> &'static str;
 }
 
+/// A strategy for sorting cards, so that games can put cards in their own order, such as with
+/// trumps first, without changing what a card is. `compare()` returns [`Ordering::Less`] for the
+/// card that should come first.
+///
+/// Built in orderings are in the [`ordering`](crate::types::ordering) module, and any closure that
+/// compares two cards is also a `CardOrdering`:
+///
+/// ```
+/// use cardpack::prelude::*;
+///
+/// let pile = French::from_str("2C AS KH").unwrap();
+/// let lowest_first = |a: &FrenchCard, b: &FrenchCard| a.rank.cmp(&b.rank);
+///
+/// assert_eq!(pile.sort_by_ordering(&lowest_first).to_string(), "2♣ K♥ A♠");
+/// ```
+pub trait CardOrdering<RankType: Ranked + Clone, SuitType: Suited + Clone> {
+    fn compare(&self, a: &Card<RankType, SuitType>, b: &Card<RankType, SuitType>) -> Ordering;
+}
+
+impl<RankType: Ranked + Clone, SuitType: Suited + Clone, F> CardOrdering<RankType, SuitType> for F
+where
+    F: Fn(&Card<RankType, SuitType>, &Card<RankType, SuitType>) -> Ordering,
+{
+    fn compare(&self, a: &Card<RankType, SuitType>, b: &Card<RankType, SuitType>) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// A way of writing down cards, such as `AS`, `Ace of Spades` or `🂡`, so that hands pasted in
+/// from other tools can be parsed. `parse()` returns `None` if the text isn't a card in the
+/// notation, and `split()` breaks a list of cards up into the text of each one.
+///
+/// Built in notations are in the [`notation`](crate::types::notation) module. A tuple of
+/// notations tries each one in turn, which is how
+/// [`Flexible`](crate::types::notation::Flexible), the notation behind `FromStr` for
+/// [`Card`] and [`Pile`], is put together.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::notation::{Glyph, LongName};
+///
+/// let notation = (LongName, Glyph);
+///
+/// assert_eq!(FrenchCard::from_str_with("ace of spades", &notation).unwrap(), card!(AS));
+/// assert_eq!(FrenchCard::from_str_with("🂡", &notation).unwrap(), card!(AS));
+/// assert!(FrenchCard::from_str_with("AS", &notation).is_err());
+/// ```
+pub trait Notation {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>>;
+
+    /// Splits a list of cards up into the text of each one. Surrounding brackets and quotes are
+    /// dropped, and the cards are split on commas or semicolons if there are any, or on
+    /// whitespace if there aren't, so `[As, Kd]`, `"AS";"KD"` and `AS KD` all work.
+    fn split<'a>(&self, index: &'a str) -> Vec<&'a str> {
+        let index = index
+            .trim()
+            .trim_start_matches(['[', '(', '{'])
+            .trim_end_matches([']', ')', '}']);
+        let cards: Vec<&str> = if index.contains([',', ';']) {
+            index.split([',', ';']).collect()
+        } else {
+            index.split_whitespace().collect()
+        };
+        cards
+            .into_iter()
+            .map(|card| card.trim().trim_matches(['"', '\'']))
+            .filter(|card| !card.is_empty())
+            .collect()
+    }
+}
+
+impl<A: Notation, B: Notation> Notation for (A, B) {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        self.0.parse(s).or_else(|| self.1.parse(s))
+    }
+
+    fn split<'a>(&self, index: &'a str) -> Vec<&'a str> {
+        self.0.split(index)
+    }
+}
+
+impl<A: Notation, B: Notation, C: Notation> Notation for (A, B, C) {
+    fn parse<RankType: Ranked + Clone, SuitType: Suited + Clone>(
+        &self,
+        s: &str,
+    ) -> Option<Card<RankType, SuitType>> {
+        self.0
+            .parse(s)
+            .or_else(|| self.1.parse(s))
+            .or_else(|| self.2.parse(s))
+    }
+
+    fn split<'a>(&self, index: &'a str) -> Vec<&'a str> {
+        self.0.split(index)
+    }
+}
+
+/// Shuffles a collection of cards in place, either with a passed in function, or with one of
+/// the physical shuffle models in [`Shuffle`]. The first card in the collection is the top of
+/// the deck.
+///
+/// ```
+/// use cardpack::prelude::*;
+/// use cardpack::types::utils::Random;
+///
+/// let mut deck = French::deck();
+/// let mut rng = Random::seeded(7);
+///
+/// deck.riffles(7, &mut rng);
+/// deck.random_cut(&mut rng);
+///
+/// assert!(deck.same(&French::deck()));
+/// assert_ne!(deck, French::deck());
+/// ```
 pub trait Shufflable<RankType: Ranked + Ord + Clone, SuitType: Suited + Ord + Clone> {
-    fn shuffle<F>(&mut self, _shuffle_fn: F)
+    /// The cards to be shuffled, with the top of the deck first.
+    fn cards_mut(&mut self) -> &mut Vec<Card<RankType, SuitType>>;
+
+    /// Shuffles the cards with the passed in function. Since [`Pile`] has its own `shuffle()`
+    /// method, this needs to be called through the trait:
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let mut pile = cards!("AS KS QS").unwrap();
+    /// Shufflable::shuffle(&mut pile, |v| v.reverse());
+    ///
+    /// assert_eq!(pile.to_string(), "Q♠ K♠ A♠");
+    /// ```
+    fn shuffle<F>(&mut self, mut shuffle_fn: F)
     where
         F: FnMut(&mut Vec<Card<RankType, SuitType>>),
     {
-        todo!()
+        shuffle_fn(self.cards_mut());
+    }
+
+    /// A single [Gilbert–Shannon–Reeds](Shuffle::riffle) riffle shuffle.
+    fn riffle<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        Shuffle::riffle(self.cards_mut(), rng);
+    }
+
+    /// Riffles the cards the passed in number of times. Seven is generally considered enough
+    /// to mix a 52 card deck.
+    fn riffles<R: RngCore + ?Sized>(&mut self, times: usize, rng: &mut R) {
+        for _ in 0..times {
+            self.riffle(rng);
+        }
+    }
+
+    /// A perfect [in faro](Shuffle::faro_in), which moves the top card to second from the top.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let mut pile = cards!("AS KS QS JS").unwrap();
+    /// pile.faro_in();
+    ///
+    /// assert_eq!(pile.to_string(), "Q♠ A♠ J♠ K♠");
+    /// ```
+    fn faro_in(&mut self) {
+        Shuffle::faro_in(self.cards_mut());
+    }
+
+    /// A perfect [out faro](Shuffle::faro_out), which keeps the top card on top.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// let mut pile = cards!("AS KS QS JS").unwrap();
+    /// pile.faro_out();
+    ///
+    /// assert_eq!(pile.to_string(), "A♠ Q♠ K♠ J♠");
+    /// ```
+    fn faro_out(&mut self) {
+        Shuffle::faro_out(self.cards_mut());
+    }
+
+    /// An [overhand](Shuffle::overhand) shuffle.
+    fn overhand<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        Shuffle::overhand(self.cards_mut(), rng);
+    }
+
+    /// A [strip](Shuffle::strip) shuffle.
+    fn strip<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        Shuffle::strip(self.cards_mut(), rng);
+    }
+
+    /// A [pile](Shuffle::pile) shuffle into the passed in number of piles.
+    fn pile_shuffle<R: RngCore + ?Sized>(&mut self, piles: usize, rng: &mut R) {
+        Shuffle::pile(self.cards_mut(), piles, rng);
+    }
+
+    /// [Cuts](Shuffle::cut) the cards somewhere near the middle.
+    fn random_cut<R: RngCore + ?Sized>(&mut self, rng: &mut R) {
+        Shuffle::cut(self.cards_mut(), rng);
     }
 }
@@ -1,3 +1,19 @@
+use crate::types::traits::Literal;
+use rand::RngCore;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// The named PRNG behind every seeded shuffle in the library.
+///
+/// [`ChaCha8Rng`] is documented by the `rand_chacha` crate as being value-stable: the same
+/// seed produces the same stream of numbers on every platform and release. That is what
+/// lets a seed in a bug report replay the exact same deal.
+pub type SeededRng = ChaCha8Rng;
+
 pub struct Bit;
 
 impl Bit {
@@ -40,22 +56,307 @@ impl Bit {
     /// These utility methods come from `pkcore`, a library that is currently a work in progress.
     #[must_use]
     pub fn string(ckc: u32) -> String {
-        let b = format!("{ckc:b}");
         // OK, let's take a moment to really stan on the rust std libraries. The fmt
         // [Fill/Alignment](https://doc.rust-lang.org/std/fmt/#fillalignment) is FIRE!
-        let b = format!("{b:0>32}");
-        let mut bit_string = String::with_capacity(34);
+        Bit::spaced(format!("{ckc:0>32b}").chars())
+    }
 
-        for (i, c) in b.chars().enumerate() {
-            bit_string.push(c);
-            if i % 8 == 7 && i % 31 != 0 {
+    /// The same as [`Bit::string()`], but for the 64 bits of a deck-aware
+    /// [`Encoding`](crate::types::encoding::Encoding).
+    #[must_use]
+    pub fn string64(bits: u64) -> String {
+        Bit::spaced(format!("{bits:0>64b}").chars())
+    }
+
+    /// Groups the chars into bytes, separated by spaces.
+    pub(crate) fn spaced(chars: impl Iterator<Item = char>) -> String {
+        let mut bit_string = String::new();
+
+        for (i, c) in chars.enumerate() {
+            if i > 0 && i % 8 == 0 {
                 bit_string.push(' ');
             }
+            bit_string.push(c);
         }
         bit_string
     }
 }
 
+/// Const checks for card indexes, used by the [`card!`](crate::card) and [`pile!`](crate::pile)
+/// macros to reject an invalid index while it's being compiled. They follow the same rules as
+/// `FromStr` for [`Card`](crate::types::card::Card) and [`Pile`](crate::types::pile::Pile): a
+/// card is a rank char followed by a suit char, and cards are separated by whitespace.
+///
+/// The `_of` versions also check that the rank and suit are from the same tier of a deck with
+/// [special suits](crate::types::traits::Suited::special_suit_names), such as the jokers of a
+/// [`Modern`](crate::decks::modern::Modern) deck.
+pub struct Literals;
+
+impl Literals {
+    /// Returns `true` if the index is a single card.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert!(Literals::is_card(" AS ", French::RANK_CHARS, French::SUIT_CHARS));
+    /// assert!(!Literals::is_card("AS KS", French::RANK_CHARS, French::SUIT_CHARS));
+    /// assert!(!Literals::is_card("BS", French::RANK_CHARS, French::SUIT_CHARS));
+    /// ```
+    #[must_use]
+    pub const fn is_card(index: &str, rank_chars: &[char], suit_chars: &[char]) -> bool {
+        matches!(
+            Self::count(index, rank_chars, suit_chars, &[], &[]),
+            Some(1)
+        )
+    }
+
+    /// Returns `true` if the index is one or more cards.
+    #[must_use]
+    pub const fn is_pile(index: &str, rank_chars: &[char], suit_chars: &[char]) -> bool {
+        matches!(Self::count(index, rank_chars, suit_chars, &[], &[]), Some(n) if n > 0)
+    }
+
+    /// Returns `true` if the index is a single card of the deck, with a
+    /// [special rank](Literal::SPECIAL_RANK_CHARS) only in a
+    /// [special suit](Literal::SPECIAL_SUIT_CHARS), and a regular rank only in a regular suit.
+    ///
+    /// ```
+    /// use cardpack::prelude::*;
+    ///
+    /// assert!(Literals::is_card_of::<Modern>("BJ"));
+    /// assert!(Literals::is_card_of::<Modern>("AS"));
+    /// assert!(!Literals::is_card_of::<Modern>("BS"));
+    /// assert!(!Literals::is_card_of::<Modern>("AJ"));
+    /// assert!(!Literals::is_card_of::<Tarot>("🤡W"));
+    /// ```
+    #[must_use]
+    pub const fn is_card_of<Deck: Literal>(index: &str) -> bool {
+        matches!(Self::count_of::<Deck>(index), Some(1))
+    }
+
+    /// Returns `true` if the index is one or more cards of the deck, following the same rules as
+    /// [`is_card_of()`](Literals::is_card_of).
+    #[must_use]
+    pub const fn is_pile_of<Deck: Literal>(index: &str) -> bool {
+        matches!(Self::count_of::<Deck>(index), Some(n) if n > 0)
+    }
+
+    const fn count_of<Deck: Literal>(index: &str) -> Option<usize> {
+        Self::count(
+            index,
+            Deck::RANK_CHARS,
+            Deck::SUIT_CHARS,
+            Deck::SPECIAL_RANK_CHARS,
+            Deck::SPECIAL_SUIT_CHARS,
+        )
+    }
+
+    /// The number of cards in the index, or `None` if any of them isn't valid, including a card
+    /// that pairs a special rank with a regular suit, or a regular rank with a special suit.
+    const fn count(
+        index: &str,
+        rank_chars: &[char],
+        suit_chars: &[char],
+        special_rank_chars: &[char],
+        special_suit_chars: &[char],
+    ) -> Option<usize> {
+        let bytes = index.as_bytes();
+        let mut i = 0;
+        let mut cards = 0;
+        // How many chars of the current card have been read.
+        let mut read = 0;
+        let mut special_rank = false;
+        while i < bytes.len() {
+            let (c, len) = Self::decode(bytes, i);
+            i += len;
+            if Self::is_whitespace(c) {
+                if read == 1 {
+                    return None;
+                }
+                read = 0;
+            } else {
+                match read {
+                    0 if Self::contains(rank_chars, c) => {
+                        special_rank = Self::contains(special_rank_chars, c);
+                    }
+                    1 if Self::contains(suit_chars, c)
+                        && special_rank == Self::contains(special_suit_chars, c) =>
+                    {
+                        cards += 1;
+                    }
+                    _ => return None,
+                }
+                read += 1;
+            }
+        }
+        if read == 1 {
+            None
+        } else {
+            Some(cards)
+        }
+    }
+
+    const fn contains(chars: &[char], c: char) -> bool {
+        let mut i = 0;
+        while i < chars.len() {
+            if chars[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Decodes the UTF-8 char starting at `i`, returning it along with its length in bytes.
+    /// `str` is always valid UTF-8, so there's no need to check the continuation bytes.
+    #[allow(clippy::cast_lossless)]
+    const fn decode(bytes: &[u8], i: usize) -> (char, usize) {
+        let first = bytes[i] as u32;
+        let (len, mut code) = match first {
+            0x00..=0x7F => (1, first),
+            0x80..=0xDF => (2, first & 0x1F),
+            0xE0..=0xEF => (3, first & 0x0F),
+            _ => (4, first & 0x07),
+        };
+        let mut n = 1;
+        while n < len {
+            code = (code << 6) | (bytes[i + n] as u32 & 0x3F);
+            n += 1;
+        }
+        match char::from_u32(code) {
+            Some(c) => (c, len),
+            None => (char::REPLACEMENT_CHARACTER, len),
+        }
+    }
+
+    /// The same chars as `char::is_whitespace()`, which `str::split_whitespace()` splits on.
+    const fn is_whitespace(c: char) -> bool {
+        matches!(
+            c,
+            '\t'..='\r'
+                | ' '
+                | '\u{85}'
+                | '\u{A0}'
+                | '\u{1680}'
+                | '\u{2000}'..='\u{200A}'
+                | '\u{2028}'
+                | '\u{2029}'
+                | '\u{202F}'
+                | '\u{205F}'
+                | '\u{3000}'
+        )
+    }
+}
+
+/// Randomization utilities that don't rely on `rand`'s own shuffling, so that the results are
+/// stable across versions of `rand`.
+pub struct Random;
+
+impl Random {
+    /// Returns a [`SeededRng`] for the passed in seed.
+    #[must_use]
+    pub fn seeded(seed: u64) -> SeededRng {
+        SeededRng::seed_from_u64(seed)
+    }
+
+    /// Returns a uniformly distributed number in `0..bound` using rejection sampling over
+    /// `next_u64()`, so that there is no modulo bias.
+    ///
+    /// Returns 0 if `bound` is 0.
+    ///
+    /// ```
+    /// use cardpack::types::utils::Random;
+    ///
+    /// let mut rng = Random::seeded(42);
+    ///
+    /// for _ in 0..100 {
+    ///     assert!(Random::below(&mut rng, 6) < 6);
+    /// }
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn below<R: RngCore + ?Sized>(rng: &mut R, bound: usize) -> usize {
+        if bound == 0 {
+            return 0;
+        }
+        let bound = bound as u64;
+        // Values below the threshold would bias the results towards the lower numbers.
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let v = rng.next_u64();
+            if v >= threshold {
+                return (v % bound) as usize;
+            }
+        }
+    }
+
+    /// The [Fisher–Yates](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle) shuffle,
+    /// Durstenfeld's version, walking the slice from the back to the front:
+    ///
+    /// ```txt
+    /// for i from n − 1 down to 1 do
+    ///      j ← random integer such that 0 ≤ j ≤ i
+    ///      exchange a[j] and a[i]
+    /// ```
+    ///
+    /// The algorithm is written out here, instead of using `SliceRandom::shuffle()`, because
+    /// `rand` doesn't promise that its shuffle will produce the same order between releases.
+    pub fn fisher_yates<T, R: RngCore + ?Sized>(v: &mut [T], rng: &mut R) {
+        for i in (1..v.len()).rev() {
+            let j = Random::below(rng, i + 1);
+            v.swap(i, j);
+        }
+    }
+}
+
+/// Values that are worked out once for each type, such as a deck's
+/// [`DeckTable`](crate::types::deck_table::DeckTable), and then kept for the rest of the program.
+///
+/// Each thread keeps its own copy of the map of values that it has already seen, so looking one
+/// up only takes the lock the first time that the thread asks for it.
+pub(crate) struct PerType;
+
+type PerTypeMap = HashMap<(TypeId, TypeId), &'static (dyn Any + Send + Sync)>;
+
+impl PerType {
+    /// Returns the value of `T` for the type `K`, building it the first time. If `build` returns
+    /// `None`, such as for a deck that hasn't been loaded yet, nothing is kept, and it's called
+    /// again the next time.
+    pub(crate) fn get_or_init<K: ?Sized + 'static, T: Any + Send + Sync>(
+        build: impl FnOnce() -> Option<T>,
+    ) -> Option<&'static T> {
+        thread_local! {
+            static LOCAL: RefCell<PerTypeMap> = RefCell::new(HashMap::new());
+        }
+        static GLOBAL: OnceLock<RwLock<PerTypeMap>> = OnceLock::new();
+
+        let key = (TypeId::of::<K>(), TypeId::of::<T>());
+        let downcast = |value: &'static (dyn Any + Send + Sync)| value.downcast_ref::<T>();
+
+        if let Some(value) = LOCAL.with(|local| local.borrow().get(&key).copied()) {
+            return downcast(value);
+        }
+
+        let global = GLOBAL.get_or_init(RwLock::default);
+        let existing = global
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .copied();
+        let value = if let Some(value) = existing {
+            value
+        } else {
+            let value = build()?;
+            *global
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(key)
+                .or_insert_with(|| Box::leak(Box::new(value)))
+        };
+        LOCAL.with(|local| local.borrow_mut().insert(key, value));
+        downcast(value)
+    }
+}
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod types__rank__tests {
@@ -76,6 +377,132 @@ mod types__rank__tests {
         assert_eq!(Bit::string(ckc), expected);
     }
 
+    #[test]
+    fn string64() {
+        assert_eq!(
+            Bit::string64(u64::MAX - 1),
+            "11111111 11111111 11111111 11111111 11111111 11111111 11111111 11111110"
+        );
+    }
+
+    #[test]
+    fn literals__is_pile() {
+        let (ranks, suits) = (&['A', '🤡'][..], &['S', '♠', 'M'][..]);
+
+        assert!(Literals::is_pile("A♠ 🤡M\tAS\n", ranks, suits));
+        assert!(Literals::is_card("🤡M", ranks, suits));
+        assert!(!Literals::is_pile("", ranks, suits));
+        assert!(!Literals::is_pile("  ", ranks, suits));
+        assert!(!Literals::is_pile("AS A", ranks, suits));
+        assert!(!Literals::is_pile("ASM", ranks, suits));
+        assert!(!Literals::is_pile("SA", ranks, suits));
+        assert!(!Literals::is_card("AS AS", ranks, suits));
+    }
+
+    #[test]
+    fn literals__is_pile_of__every_card_in_the_deck() {
+        use crate::decks::modern::Modern;
+        use crate::decks::tarot::Tarot;
+        use crate::types::traits::Decked;
+
+        let modern = Modern::deck().index();
+        // A couple of the trumps' emojis are more than one char, so they can't be literals.
+        let tarot = Tarot::deck()
+            .iter()
+            .map(|card| card.index)
+            .filter(|index| index.chars().count() == 2)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        assert!(Literals::is_pile_of::<Modern>(&modern));
+        assert!(Literals::is_pile_of::<Tarot>(&tarot));
+        assert!(!Literals::is_pile_of::<Modern>("AS LS"));
+        assert!(!Literals::is_pile_of::<Tarot>("🤡M AM"));
+    }
+
+    /// Every char of the deck's literals has to be one of its ranks or suits, and the special
+    /// ones one of its special ranks or suits, or `card!` would make a blank card.
+    fn assert_literal_chars<Deck: Literal>() {
+        use crate::types::traits::{Ranked, Suited};
+
+        let special_ranks = Deck::RankType::special_rank_names();
+        let special_suits = Deck::SuitType::special_suit_names();
+        for c in Deck::RANK_CHARS {
+            let rank = Deck::RankType::rank_for_char(c);
+            assert!(rank.is_some(), "{}: {c}", Deck::RankType::type_name());
+            assert_eq!(
+                special_ranks.contains(&rank.unwrap()),
+                Deck::SPECIAL_RANK_CHARS.contains(c),
+                "{}: {c}",
+                Deck::RankType::type_name()
+            );
+        }
+        for c in Deck::SUIT_CHARS {
+            let suit = Deck::SuitType::suit_for_char(c);
+            assert!(suit.is_some(), "{}: {c}", Deck::SuitType::type_name());
+            assert_eq!(
+                special_suits.contains(&suit.unwrap()),
+                Deck::SPECIAL_SUIT_CHARS.contains(c),
+                "{}: {c}",
+                Deck::SuitType::type_name()
+            );
+        }
+        assert!(Deck::SPECIAL_RANK_CHARS
+            .iter()
+            .all(|c| Deck::RANK_CHARS.contains(c)));
+        assert!(Deck::SPECIAL_SUIT_CHARS
+            .iter()
+            .all(|c| Deck::SUIT_CHARS.contains(c)));
+    }
+
+    #[test]
+    fn literals__chars_are_the_decks() {
+        use crate::decks::canasta::Canasta;
+        use crate::decks::euchre24::Euchre24;
+        use crate::decks::french::French;
+        use crate::decks::hand_and_foot::HandAndFoot;
+        use crate::decks::modern::Modern;
+        use crate::decks::pinochle::Pinochle;
+        use crate::decks::short::Short;
+        use crate::decks::skat::Skat;
+        use crate::decks::spades::Spades;
+        use crate::decks::tarot::Tarot;
+
+        assert_literal_chars::<Canasta>();
+        assert_literal_chars::<Euchre24>();
+        assert_literal_chars::<French>();
+        assert_literal_chars::<HandAndFoot>();
+        assert_literal_chars::<Modern>();
+        assert_literal_chars::<Pinochle>();
+        assert_literal_chars::<Short>();
+        assert_literal_chars::<Skat>();
+        assert_literal_chars::<Spades>();
+        assert_literal_chars::<Tarot>();
+    }
+
+    #[test]
+    fn random__below() {
+        let mut rng = Random::seeded(1);
+
+        assert_eq!(Random::below(&mut rng, 0), 0);
+        assert_eq!(Random::below(&mut rng, 1), 0);
+        for _ in 0..1_000 {
+            assert!(Random::below(&mut rng, 52) < 52);
+        }
+    }
+
+    #[test]
+    fn random__fisher_yates() {
+        let mut v: Vec<usize> = (0..10).collect();
+        Random::fisher_yates(&mut v, &mut Random::seeded(7));
+
+        let mut sorted = v.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, (0..10).collect::<Vec<usize>>());
+        assert_ne!(v, sorted);
+    }
+
     #[test]
     fn only_suit_flags() {
         let ckc = 0b1111_1111_1111_1111_1111_1111_1111_1111;